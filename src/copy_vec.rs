@@ -0,0 +1,180 @@
+use crate::{iter::IntoIter, ArrayVec};
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    hash::{Hash, Hasher},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+/// An array backed vector of [`Copy`] items, that is [`Copy`] itself
+///
+/// [`ArrayVec`](type@ArrayVec) can't be [`Copy`], because [`GenericVec`](crate::GenericVec)
+/// implements [`Drop`]. `CopyArrayVec` has the same layout as an
+/// [`ArrayVec`](type@ArrayVec), and dereferences to one, so it has the
+/// full [`GenericVec`](crate::GenericVec) API. Copying or cloning it is a single memcpy.
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::CopyArrayVec;
+///
+/// #[derive(Clone, Copy)]
+/// struct Polygon {
+///     points: CopyArrayVec<(f32, f32), 8>,
+/// }
+///
+/// let mut triangle = Polygon { points: CopyArrayVec::new() };
+/// triangle.points.push((0.0, 0.0));
+/// triangle.points.push((1.0, 0.0));
+/// triangle.points.push((0.0, 1.0));
+///
+/// let mut square = triangle;
+/// square.points.insert(2, (1.0, 1.0));
+///
+/// assert_eq!(triangle.points.len(), 3);
+/// assert_eq!(square.points, [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+/// # }
+/// ```
+// must have the same layout as `GenericVec`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CopyArrayVec<T: Copy, const N: usize> {
+    len: usize,
+    storage: [MaybeUninit<T>; N],
+}
+
+impl<T: Copy, const N: usize> CopyArrayVec<T, N> {
+    /// Create a new empty `CopyArrayVec`
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            storage: crate::uninit_array(),
+        }
+    }
+
+    /// Create a new full `CopyArrayVec`
+    pub const fn from_array(array: [T; N]) -> Self { Self::from_array_vec(ArrayVec::from_array(array)) }
+
+    /// Convert an [`ArrayVec`](type@ArrayVec) into a `CopyArrayVec`
+    pub const fn from_array_vec(vec: ArrayVec<T, N>) -> Self {
+        let vec = core::mem::ManuallyDrop::new(vec);
+
+        // Safety: `CopyArrayVec` has the same layout as `ArrayVec`
+        unsafe { core::ptr::addr_of!(vec).cast::<Self>().read() }
+    }
+
+    /// Convert this `CopyArrayVec` into an [`ArrayVec`](type@ArrayVec)
+    pub const fn into_array_vec(self) -> ArrayVec<T, N> {
+        // Safety: the first `len` items are initialized
+        unsafe { ArrayVec::from_raw_parts(self.len, self.storage) }
+    }
+}
+
+impl<T: Copy, const N: usize> Deref for CopyArrayVec<T, N> {
+    type Target = ArrayVec<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: `CopyArrayVec` has the same layout as `ArrayVec`
+        unsafe { &*core::ptr::addr_of!(*self).cast::<ArrayVec<T, N>>() }
+    }
+}
+
+impl<T: Copy, const N: usize> DerefMut for CopyArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety
+        //
+        // * `CopyArrayVec` has the same layout as `ArrayVec`
+        // * `T: Copy`, so dropping or replacing the `ArrayVec` doesn't run any destructors
+        unsafe { &mut *core::ptr::addr_of_mut!(*self).cast::<ArrayVec<T, N>>() }
+    }
+}
+
+impl<T: Copy, const N: usize> Default for CopyArrayVec<T, N> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Copy, const N: usize> From<[T; N]> for CopyArrayVec<T, N> {
+    fn from(array: [T; N]) -> Self { Self::from_array(array) }
+}
+
+impl<T: Copy, const N: usize> From<ArrayVec<T, N>> for CopyArrayVec<T, N> {
+    fn from(vec: ArrayVec<T, N>) -> Self { Self::from_array_vec(vec) }
+}
+
+impl<T: Copy, const N: usize> From<CopyArrayVec<T, N>> for ArrayVec<T, N> {
+    fn from(vec: CopyArrayVec<T, N>) -> Self { vec.into_array_vec() }
+}
+
+impl<O: ?Sized + AsRef<[T]>, T: Copy + PartialEq, const N: usize> PartialEq<O> for CopyArrayVec<T, N> {
+    fn eq(&self, other: &O) -> bool { self.as_slice() == other.as_ref() }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for CopyArrayVec<T, N> {}
+
+impl<O: ?Sized + AsRef<[T]>, T: Copy + PartialOrd, const N: usize> PartialOrd<O> for CopyArrayVec<T, N> {
+    fn partial_cmp(&self, other: &O) -> Option<core::cmp::Ordering> { self.as_slice().partial_cmp(other.as_ref()) }
+}
+
+impl<T: Copy + Ord, const N: usize> Ord for CopyArrayVec<T, N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering { self.as_slice().cmp(other.as_slice()) }
+}
+
+impl<T: Copy + Hash, const N: usize> Hash for CopyArrayVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+}
+
+impl<T: Copy + fmt::Debug, const N: usize> fmt::Debug for CopyArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.as_slice().fmt(f) }
+}
+
+impl<T: Copy, const N: usize> AsRef<[T]> for CopyArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] { self }
+}
+
+impl<T: Copy, const N: usize> AsMut<[T]> for CopyArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] { self }
+}
+
+impl<T: Copy, const N: usize> Borrow<[T]> for CopyArrayVec<T, N> {
+    fn borrow(&self) -> &[T] { self }
+}
+
+impl<T: Copy, const N: usize> BorrowMut<[T]> for CopyArrayVec<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] { self }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl<T: Copy, const N: usize> Extend<T> for CopyArrayVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) { (**self).extend(iter) }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl<T: Copy, const N: usize> core::iter::FromIterator<T> for CopyArrayVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T: Copy, const N: usize> IntoIterator for CopyArrayVec<T, N> {
+    type IntoIter = IntoIter<[MaybeUninit<T>; N]>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter { self.into_array_vec().into_iter() }
+}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a CopyArrayVec<T, N> {
+    type IntoIter = core::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a mut CopyArrayVec<T, N> {
+    type IntoIter = core::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
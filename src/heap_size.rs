@@ -0,0 +1,205 @@
+use crate::{raw::Storage, GenericVec};
+
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use std::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    string::String,
+    vec::Vec,
+};
+
+/// A value that can report how much heap memory it owns
+///
+/// The heap size does not include the size of the value itself, only the
+/// memory that it owns through pointers, and the memory owned by the values in
+/// that memory, recursively.
+///
+/// [`GenericVec`] reports the memory owned by its storage (see [`Storage::heap_bytes`]),
+/// which is the capacity times the size of an item for heap storages, and nothing
+/// for array and slice storages, plus the heap size of every item.
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{gvec, ArrayVec, HeapSize, HeapVec};
+///
+/// let mut names = HeapVec::<HeapVec<String>>::with_capacity(2);
+/// names.push(gvec![String::from("hello")]);
+///
+/// let inner = names[0].capacity() * core::mem::size_of::<String>() + names[0][0].capacity();
+/// let outer = names.capacity() * core::mem::size_of::<HeapVec<String>>();
+/// assert_eq!(names.heap_size(), outer + inner);
+///
+/// let mut array = ArrayVec::<String, 4>::new();
+/// array.push(String::from("hello"));
+/// assert_eq!(array.heap_size(), 5);
+/// # }
+/// ```
+///
+/// Reference counted pointers like [`Rc`](std::rc::Rc) don't implement this trait,
+/// because their memory is shared, and is not owned by any single value. Shared
+/// storages (like [`ArcStorage`](crate::raw::ArcStorage)) count their buffer in every
+/// vector that shares it.
+pub trait HeapSize {
+    /// The number of bytes of heap memory that this value owns
+    fn heap_size(&self) -> usize;
+}
+
+impl<T: HeapSize, S: ?Sized + Storage<Item = T>> HeapSize for GenericVec<T, S> {
+    fn heap_size(&self) -> usize { self.storage.heap_bytes() + self.as_slice().heap_size() }
+}
+
+macro_rules! no_heap {
+    ($($type:ty),* $(,)?) => {$(
+        impl HeapSize for $type {
+            #[inline]
+            fn heap_size(&self) -> usize { 0 }
+        }
+    )*};
+}
+
+no_heap! {
+    (), bool, char, str,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+}
+
+impl<T: ?Sized> HeapSize for PhantomData<T> {
+    #[inline]
+    fn heap_size(&self) -> usize { 0 }
+}
+
+/// References don't own the value they point to
+impl<T: ?Sized> HeapSize for &T {
+    #[inline]
+    fn heap_size(&self) -> usize { 0 }
+}
+
+/// References don't own the value they point to
+impl<T: ?Sized> HeapSize for &mut T {
+    #[inline]
+    fn heap_size(&self) -> usize { 0 }
+}
+
+impl<T: HeapSize> HeapSize for [T] {
+    fn heap_size(&self) -> usize { self.iter().map(HeapSize::heap_size).sum() }
+}
+
+impl<T: HeapSize, const N: usize> HeapSize for [T; N] {
+    fn heap_size(&self) -> usize { self.as_slice().heap_size() }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize { self.as_ref().map_or(0, HeapSize::heap_size) }
+}
+
+impl<T: HeapSize, E: HeapSize> HeapSize for Result<T, E> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Ok(value) => value.heap_size(),
+            Err(error) => error.heap_size(),
+        }
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident)*) => {
+        impl<$($name: HeapSize),*> HeapSize for ($($name,)*) {
+            #[allow(non_snake_case)]
+            fn heap_size(&self) -> usize {
+                let ($($name,)*) = self;
+                0 $(+ $name.heap_size())*
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+tuple!(A B C D E F G);
+tuple!(A B C D E F G H);
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize { core::mem::size_of_val::<T>(self) + T::heap_size(self) }
+}
+
+#[cfg(feature = "alloc")]
+impl HeapSize for String {
+    fn heap_size(&self) -> usize { self.capacity() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize { self.capacity() * size_of::<T>() + self.as_slice().heap_size() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+/// The nodes of a [`BTreeMap`] are not exposed, so this only counts the space
+/// taken up by the entries, and not the unused space in the nodes
+#[cfg(feature = "alloc")]
+impl<K: HeapSize, V: HeapSize> HeapSize for BTreeMap<K, V> {
+    fn heap_size(&self) -> usize {
+        self.len() * size_of::<(K, V)>()
+            + self
+                .iter()
+                .map(|(key, value)| key.heap_size() + value.heap_size())
+                .sum::<usize>()
+    }
+}
+
+/// The nodes of a [`BTreeSet`] are not exposed, so this only counts the space
+/// taken up by the entries, and not the unused space in the nodes
+#[cfg(feature = "alloc")]
+impl<T: HeapSize> HeapSize for BTreeSet<T> {
+    fn heap_size(&self) -> usize { self.len() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>() }
+}
+
+/// The table of a [`HashMap`](std::collections::HashMap) is not exposed, so this
+/// counts one control byte and one entry for every slot of its capacity
+#[cfg(feature = "std")]
+impl<K: HeapSize, V: HeapSize, H> HeapSize for std::collections::HashMap<K, V, H> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * (size_of::<(K, V)>() + 1)
+            + self
+                .iter()
+                .map(|(key, value)| key.heap_size() + value.heap_size())
+                .sum::<usize>()
+    }
+}
+
+/// The table of a [`HashSet`](std::collections::HashSet) is not exposed, so this
+/// counts one control byte and one entry for every slot of its capacity
+#[cfg(feature = "std")]
+impl<T: HeapSize, H> HeapSize for std::collections::HashSet<T, H> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * (size_of::<T>() + 1) + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized + ToOwned> HeapSize for Cow<'_, B>
+where
+    B::Owned: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        match self {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(owned) => owned.heap_size(),
+        }
+    }
+}
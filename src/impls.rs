@@ -10,8 +10,9 @@ use core::{
 };
 
 #[cfg(feature = "alloc")]
-use std::vec::Vec;
+use std::{borrow::Cow, boxed::Box, collections::VecDeque, rc::Rc, sync::Arc, vec::Vec};
 
+#[cfg(not(feature = "no-panic"))]
 impl<S: StorageWithCapacity> Clone for SimpleVec<S>
 where
     S::Item: Clone,
@@ -25,6 +26,52 @@ where
     fn clone_from(&mut self, source: &Self) { self.clone_from(source); }
 }
 
+macro_rules! shared_vec {
+    ($vec:ident, $storage:ident) => {
+        #[cfg(feature = "alloc")]
+        impl<T: Clone> Clone for crate::$vec<T> {
+            fn clone(&self) -> Self {
+                let len = self.len();
+                // Safety: the first `len` items of the vector are initialized
+                unsafe { Self::from_raw_parts(len, self.storage().share(len)) }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T: Clone> Default for crate::$vec<T> {
+            fn default() -> Self { Self::new() }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T: Clone> From<crate::HeapVec<T>> for crate::$vec<T> {
+            fn from(vec: crate::HeapVec<T>) -> Self {
+                let (len, heap) = vec.into_raw_parts();
+                unsafe { Self::from_raw_parts(len, crate::raw::$storage::from_heap(heap, 0)) }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[cfg(not(feature = "no-panic"))]
+        impl<T: Clone> From<crate::$vec<T>> for crate::HeapVec<T> {
+            fn from(vec: crate::$vec<T>) -> Self {
+                let (len, storage) = vec.into_raw_parts();
+                match storage.try_into_heap() {
+                    Ok(heap) => unsafe { Self::from_raw_parts(len, heap) },
+                    Err(storage) => {
+                        let vec = unsafe { crate::$vec::from_raw_parts(len, storage) };
+                        let mut heap = Self::with_capacity(vec.capacity());
+                        heap.extend_from_slice(&vec);
+                        heap
+                    }
+                }
+            }
+        }
+    };
+}
+
+shared_vec!(ArcVec, ArcStorage);
+shared_vec!(RcVec, RcStorage);
+
 impl<S: StorageWithCapacity + Default> Default for SimpleVec<S> {
     fn default() -> Self { Self::with_storage(Default::default()) }
 }
@@ -83,12 +130,10 @@ impl<S: ?Sized + Storage> BorrowMut<[S::Item]> for SimpleVec<S> {
     fn borrow_mut(&mut self) -> &mut [S::Item] { self }
 }
 
-#[cfg(any(doc, feature = "nightly"))]
 impl<T, const N: usize> From<[T; N]> for crate::ArrayVec<T, N> {
     fn from(array: [T; N]) -> Self { Self::from_array(array) }
 }
 
-#[cfg(any(doc, feature = "nightly"))]
 impl<T, const N: usize> TryFrom<crate::ArrayVec<T, N>> for [T; N] {
     type Error = crate::ArrayVec<T, N>;
 
@@ -148,6 +193,101 @@ impl<T, A: std::alloc::Allocator> From<crate::HeapVec<T, A>> for Vec<T, A> {
     }
 }
 
+impl<'a, T: Clone, const N: usize> TryFrom<&'a [T]> for crate::ArrayVec<T, N> {
+    type Error = &'a [T];
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        if slice.len() > N {
+            return Err(slice)
+        }
+
+        let mut vec = Self::new();
+        // Safety: the array has space for the whole slice
+        unsafe { vec.extend_from_slice_reserved(slice) }
+        Ok(vec)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryFrom<crate::HeapVec<T>> for crate::ArrayVec<T, N> {
+    type Error = crate::HeapVec<T>;
+
+    fn try_from(vec: crate::HeapVec<T>) -> Result<Self, Self::Error> { vec.try_convert() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryFrom<Vec<T>> for crate::ArrayVec<T, N> {
+    type Error = Vec<T>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> { crate::HeapVec::from(vec).try_convert().map_err(Vec::from) }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(not(feature = "no-panic"))]
+impl<T, const N: usize> From<crate::ArrayVec<T, N>> for crate::HeapVec<T> {
+    fn from(vec: crate::ArrayVec<T, N>) -> Self { vec.convert() }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(not(feature = "no-panic"))]
+impl<T, const N: usize> From<crate::ArrayVec<T, N>> for Vec<T> {
+    fn from(vec: crate::ArrayVec<T, N>) -> Self { crate::HeapVec::from(vec).into() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Box<[T]>> for crate::HeapVec<T> {
+    fn from(slice: Box<[T]>) -> Self { slice.into_vec().into() }
+}
+
+/// Shrinks the buffer to fit the items first, like [`Vec::into_boxed_slice`]
+#[cfg(feature = "alloc")]
+impl<T> From<crate::HeapVec<T>> for Box<[T]> {
+    fn from(vec: crate::HeapVec<T>) -> Self { Vec::from(vec).into_boxed_slice() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<VecDeque<T>> for crate::HeapVec<T> {
+    fn from(deque: VecDeque<T>) -> Self { Vec::from(deque).into() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<crate::HeapVec<T>> for VecDeque<T> {
+    fn from(vec: crate::HeapVec<T>) -> Self { Vec::from(vec).into() }
+}
+
+/// Reuses the buffer of owned slices, and clones borrowed slices
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<Cow<'_, [T]>> for crate::HeapVec<T> {
+    fn from(slice: Cow<'_, [T]>) -> Self { slice.into_owned().into() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<crate::HeapVec<T>> for Cow<'_, [T]> {
+    fn from(vec: crate::HeapVec<T>) -> Self { Cow::Owned(vec.into()) }
+}
+
+/// The items are cloned, because the buffer of an [`Rc`] also holds the reference counts
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<Rc<[T]>> for crate::HeapVec<T> {
+    fn from(slice: Rc<[T]>) -> Self { slice.to_vec().into() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<crate::HeapVec<T>> for Rc<[T]> {
+    fn from(vec: crate::HeapVec<T>) -> Self { Vec::from(vec).into() }
+}
+
+/// The items are cloned, because the buffer of an [`Arc`] also holds the reference counts
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<Arc<[T]>> for crate::HeapVec<T> {
+    fn from(slice: Arc<[T]>) -> Self { slice.to_vec().into() }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<crate::HeapVec<T>> for Arc<[T]> {
+    fn from(vec: crate::HeapVec<T>) -> Self { Vec::from(vec).into() }
+}
+
 impl<S: Storage + ?Sized, I> Index<I> for SimpleVec<S>
 where
     I: SliceIndex<[S::Item]>,
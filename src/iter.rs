@@ -1,26 +1,33 @@
 //! The [`Iterator`] types that can be created from a [`GenericVec`]
 
+mod collect;
 mod cursor;
 mod drain;
 mod drain_filter;
 mod into_iter;
 mod raw_cursor;
+#[cfg(not(feature = "no-panic"))]
 mod splice;
 
+pub use collect::{CollectTarget, IteratorExt};
 pub use cursor::Cursor;
 pub use drain::Drain;
-pub use drain_filter::DrainFilter;
+pub use drain_filter::{DrainFilter, DrainFilterIndexed, TryDrainFilter};
 pub use into_iter::IntoIter;
 pub use raw_cursor::RawCursor;
+#[cfg(not(feature = "no-panic"))]
 pub use splice::Splice;
 
+#[cfg(not(feature = "no-panic"))]
 use core::iter::FromIterator;
 
+#[cfg(not(feature = "no-panic"))]
 use crate::{
     raw::{Storage, StorageWithCapacity},
     SimpleVec,
 };
 
+#[cfg(not(feature = "no-panic"))]
 impl<V, S: StorageWithCapacity + Default> FromIterator<V> for SimpleVec<S>
 where
     Self: Extend<V>,
@@ -33,6 +40,7 @@ where
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl<S: ?Sized + Storage> Extend<S::Item> for SimpleVec<S> {
     fn extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) {
         let iter = iter.into_iter();
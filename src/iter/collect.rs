@@ -0,0 +1,136 @@
+use crate::{
+    raw::{Storage, StorageWithCapacity},
+    GenericVec, SimpleVec, SliceVec,
+};
+
+use core::{
+    iter::{Chain, Once},
+    mem::MaybeUninit,
+};
+
+/// A vector that an iterator can be collected into with [`IteratorExt`]
+///
+/// This is implemented for every [`GenericVec`] with a storage that
+/// implements [`StorageWithCapacity`]
+pub trait CollectTarget: Sized {
+    /// The storage of the vector
+    type Storage: StorageWithCapacity;
+
+    #[doc(hidden)]
+    fn __from_vec(vec: SimpleVec<Self::Storage>) -> Self;
+}
+
+impl<T, S: StorageWithCapacity<Item = T>> CollectTarget for GenericVec<T, S> {
+    type Storage = S;
+
+    #[doc(hidden)]
+    fn __from_vec(vec: SimpleVec<Self::Storage>) -> Self { vec }
+}
+
+/// Create a vector with space for at least `capacity` items, if that is possible
+fn with_capacity_hint<S: StorageWithCapacity>(capacity: usize) -> SimpleVec<S> {
+    // creating an empty storage never fails
+    let storage = S::try_with_capacity(capacity).unwrap_or_else(|_| S::with_capacity(0));
+    GenericVec::with_storage(storage)
+}
+
+/// Push items until the vector is full and can't reserve more space, or the iterator is exhausted
+fn extend_truncating<S: ?Sized + Storage, I: Iterator<Item = S::Item>>(vec: &mut SimpleVec<S>, mut iter: I) {
+    let _ = vec.try_reserve(iter.size_hint().0);
+
+    while !vec.is_full() || vec.try_reserve(1).is_ok() {
+        match iter.next() {
+            // Safety: there is space for at least 1 more element
+            Some(value) => unsafe {
+                vec.push_unchecked(value);
+            },
+            None => break,
+        }
+    }
+}
+
+/// Extension methods to collect an [`Iterator`] into a [`GenericVec`] without panicking
+///
+/// Unlike [`FromIterator`], these methods don't panic when the vector runs
+/// out of capacity, which makes them suitable for fixed capacity vectors
+/// like [`ArrayVec`](type@crate::ArrayVec) and [`SliceVec`]
+///
+/// ```rust
+/// use cl_generic_vec::{iter::IteratorExt, ArrayVec};
+///
+/// let vec = (0..3).try_collect_into::<ArrayVec<_, 4>>().unwrap();
+/// assert_eq!(vec, [0, 1, 2]);
+///
+/// let (vec, rest) = (0..6).try_collect_into::<ArrayVec<_, 4>>().unwrap_err();
+/// assert_eq!(vec, [0, 1, 2, 3]);
+/// assert!(rest.eq([4, 5]));
+///
+/// let vec = (0..6).collect_truncating::<ArrayVec<_, 4>>();
+/// assert_eq!(vec, [0, 1, 2, 3]);
+/// ```
+pub trait IteratorExt: Iterator + Sized {
+    /// Collect all the items of the iterator into a new vector
+    ///
+    /// # Errors
+    ///
+    /// If the vector is full and can't reserve more space, returns the full vector
+    /// and the rest of the iterator, starting with the item that didn't fit
+    #[allow(clippy::type_complexity)]
+    fn try_collect_into<V: CollectTarget>(self) -> Result<V, (V, Chain<Once<Self::Item>, Self>)>
+    where
+        V::Storage: Storage<Item = Self::Item>,
+    {
+        let mut vec = with_capacity_hint::<V::Storage>(self.size_hint().0);
+
+        match vec.try_extend(self) {
+            Ok(()) => Ok(V::__from_vec(vec)),
+            Err(rest) => Err((V::__from_vec(vec), rest)),
+        }
+    }
+
+    /// Collect the items of the iterator into a new vector, until it is full
+    ///
+    /// The remaining items are not pulled from the iterator, and are dropped
+    /// along with the iterator
+    fn collect_truncating<V: CollectTarget>(self) -> V
+    where
+        V::Storage: Storage<Item = Self::Item>,
+    {
+        let mut vec = with_capacity_hint::<V::Storage>(self.size_hint().0);
+        extend_truncating(&mut vec, self);
+        V::__from_vec(vec)
+    }
+
+    /// Collect all the items of the iterator into a [`SliceVec`] backed by `buffer`
+    ///
+    /// ```rust
+    /// use cl_generic_vec::{iter::IteratorExt, uninit_array};
+    ///
+    /// let mut buffer = uninit_array::<_, 4>();
+    /// let vec = (0..3).map(|x| x * 2).collect_in(&mut buffer).unwrap();
+    /// assert_eq!(vec, [0, 2, 4]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the buffer is full, returns the full vector and the rest of the iterator,
+    /// starting with the item that didn't fit
+    #[allow(clippy::type_complexity)]
+    fn collect_in(
+        self,
+        buffer: &mut [MaybeUninit<Self::Item>],
+    ) -> Result<SliceVec<'_, Self::Item>, (SliceVec<'_, Self::Item>, Chain<Once<Self::Item>, Self>)> {
+        // Safety
+        //
+        // The vector starts out empty, so anything that was already in
+        // the buffer is never read or dropped
+        let mut vec = unsafe { SliceVec::new(buffer) };
+
+        match vec.try_extend(self) {
+            Ok(()) => Ok(vec),
+            Err(rest) => Err((vec, rest)),
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
@@ -26,6 +26,11 @@ impl<'a, S: ?Sized + Storage> Cursor<'a, S> {
     #[inline]
     pub fn is_empty(&self) -> bool { self.raw.is_empty() }
 
+    /// The indices that the remaining elements in range of this `Cursor`
+    /// had in the vector, before the `Cursor` was created
+    #[inline]
+    pub fn remaining_range(&self) -> core::ops::Range<usize> { self.raw.remaining_range() }
+
     /// Returns `true` if the `Cursor` is has no unfilled slots
     /// and the `Cursor` is empty
     #[inline]
@@ -425,6 +430,7 @@ impl<'a, S: ?Sized + Storage> Cursor<'a, S> {
     /// * Panics if the `Cursor` is not empty
     /// * May panic if the underlying [`GenericVec`] cannot
     ///   reserve more space
+    #[cfg(not(feature = "no-panic"))]
     pub fn reserve(&mut self, space: usize) {
         assert!(self.is_empty(), "You can only call `reserve` on a empty `Cursor`");
         self.raw.reserve(space);
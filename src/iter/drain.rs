@@ -1,6 +1,6 @@
-use crate::{iter::RawCursor, Storage};
+use crate::{iter::RawCursor, raw::StorageWithCapacity, GenericVec, Storage};
 
-use core::iter::FusedIterator;
+use core::{iter::FusedIterator, mem::ManuallyDrop};
 
 /// This struct is created by [`GenericVec::drain`](crate::GenericVec::drain).
 /// See its documentation for more.
@@ -10,6 +10,101 @@ pub struct Drain<'a, S: ?Sized + Storage> {
 
 impl<'a, S: ?Sized + Storage> Drain<'a, S> {
     pub(crate) fn new(raw: RawCursor<'a, S>) -> Self { Self { raw } }
+
+    /// Returns the remaining items of this iterator as a slice
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<char, 4>::from_array(['a', 'b', 'c', 'd']);
+    /// let mut drain = vec.drain(1..);
+    /// assert_eq!(drain.as_slice(), ['b', 'c', 'd']);
+    /// drain.next();
+    /// assert_eq!(drain.as_slice(), ['c', 'd']);
+    /// ```
+    pub fn as_slice(&self) -> &[S::Item] { self.raw.as_slice() }
+
+    /// Returns the remaining items of this iterator as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [S::Item] { self.raw.as_mut_slice() }
+
+    /// Keep the items that were not yet yielded in the vector
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<char, 4>::from_array(['a', 'b', 'c', 'd']);
+    /// let mut drain = vec.drain(..);
+    /// assert_eq!(drain.next(), Some('a'));
+    /// assert_eq!(drain.next_back(), Some('d'));
+    /// drain.keep_rest();
+    /// assert_eq!(vec, ['b', 'c']);
+    /// ```
+    pub fn keep_rest(self) {
+        let this = ManuallyDrop::new(self);
+        // Safety: `this` is never used again, and dropping the
+        // cursor keeps the remaining items in the vector
+        drop(unsafe { core::ptr::read(core::ptr::addr_of!(this.raw)) });
+    }
+
+    /// Moves the items that were not yet yielded into a new vector
+    ///
+    /// ```rust
+    /// use cl_generic_vec::{ArrayVec, HeapVec};
+    ///
+    /// let mut vec = ArrayVec::<i32, 4>::from_array([1, 2, 3, 4]);
+    /// let mut drain = vec.drain(..3);
+    /// drain.next();
+    /// let rest: HeapVec<_> = drain.into_vec();
+    /// assert_eq!(rest, [2, 3]);
+    /// assert_eq!(vec, [4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic if the new vector can't hold the remaining items
+    #[cfg(not(feature = "no-panic"))]
+    pub fn into_vec<B: StorageWithCapacity<Item = S::Item>>(mut self) -> GenericVec<S::Item, B> {
+        let mut vec = GenericVec::with_capacity(self.raw.len());
+        // Safety: the new vector has enough capacity for all the items
+        unsafe { self.move_into_reserved(&mut vec) }
+        vec
+    }
+
+    /// Tries to move the items that were not yet yielded into a new vector
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(self)` if it's not possible to create a storage that can
+    /// hold the remaining items
+    pub fn try_into_vec<B: StorageWithCapacity<Item = S::Item>>(mut self) -> Result<GenericVec<S::Item, B>, Self> {
+        match GenericVec::try_with_capacity(self.raw.len()) {
+            Ok(mut vec) => {
+                // Safety: the new vector has enough capacity for all the items
+                unsafe { self.move_into_reserved(&mut vec) }
+                Ok(vec)
+            }
+            Err(_) => Err(self),
+        }
+    }
+
+    /// Moves all the remaining items into `vec`
+    ///
+    /// # Safety
+    ///
+    /// `vec` must have enough remaining capacity for all the remaining items
+    unsafe fn move_into_reserved<B: ?Sized + Storage<Item = S::Item>>(&mut self, vec: &mut GenericVec<S::Item, B>) {
+        let len = self.raw.len();
+
+        // Safety
+        //
+        // * the caller ensures there is enough capacity
+        // * the remaining items are moved into `vec`, so they
+        //   are removed from the cursor without dropping them
+        unsafe {
+            vec.extend_from_slice_unchecked(self.raw.as_slice());
+            self.raw.forget_n_front(len);
+        }
+    }
 }
 
 impl<S: ?Sized + Storage> FusedIterator for Drain<'_, S> {}
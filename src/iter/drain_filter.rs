@@ -1,17 +1,54 @@
 use crate::{iter::RawCursor, Storage};
 
-use core::iter::FusedIterator;
+use core::{convert::Infallible, iter::FusedIterator, ops::ControlFlow};
 
-/// This struct is created by [`GenericVec::drain_filter`](crate::GenericVec::drain_filter).
-/// See its documentation for more.
-pub struct DrainFilter<'a, S, F>
-where
-    S: ?Sized + Storage,
-    F: FnMut(&mut S::Item) -> bool,
-{
+/// Decides which items a [`Filter`] removes from the vector
+trait Decide<T> {
+    /// The value that stops the filter early
+    type Break;
+
+    /// `Continue(true)` removes the item at `index`, `Continue(false)` keeps it,
+    /// and `Break(_)` stops the filter
+    fn decide(&mut self, index: usize, value: &mut T) -> ControlFlow<Self::Break, bool>;
+}
+
+/// A filter that only sees the items
+struct ByValue<F>(F);
+
+impl<T, F: FnMut(&mut T) -> bool> Decide<T> for ByValue<F> {
+    type Break = Infallible;
+
+    fn decide(&mut self, _: usize, value: &mut T) -> ControlFlow<Infallible, bool> {
+        ControlFlow::Continue((self.0)(value))
+    }
+}
+
+/// A filter that sees the items and their original index
+struct ByIndex<F>(F);
+
+impl<T, F: FnMut(usize, &mut T) -> bool> Decide<T> for ByIndex<F> {
+    type Break = Infallible;
+
+    fn decide(&mut self, index: usize, value: &mut T) -> ControlFlow<Infallible, bool> {
+        ControlFlow::Continue((self.0)(index, value))
+    }
+}
+
+/// A filter that can stop early
+struct ByControlFlow<F>(F);
+
+impl<T, B, F: FnMut(&mut T) -> ControlFlow<B, bool>> Decide<T> for ByControlFlow<F> {
+    type Break = B;
+
+    fn decide(&mut self, _: usize, value: &mut T) -> ControlFlow<B, bool> { (self.0)(value) }
+}
+
+/// The iterator that all of the drain filters are built on
+struct Filter<'a, S: ?Sized + Storage, D: Decide<S::Item>> {
     raw: RawCursor<'a, S>,
-    filter: F,
+    decide: D,
     panicking: bool,
+    stopped: bool,
 }
 
 struct SetOnDrop<'a>(&'a mut bool);
@@ -20,25 +57,26 @@ impl<'a> Drop for SetOnDrop<'a> {
     fn drop(&mut self) { *self.0 = true; }
 }
 
-impl<'a, S, F> DrainFilter<'a, S, F>
-where
-    S: ?Sized + Storage,
-    F: FnMut(&mut S::Item) -> bool,
-{
-    pub(crate) fn new(raw: RawCursor<'a, S>, filter: F) -> Self {
+impl<'a, S: ?Sized + Storage, D: Decide<S::Item>> Filter<'a, S, D> {
+    fn new(raw: RawCursor<'a, S>, decide: D) -> Self {
         Self {
             raw,
-            filter,
+            decide,
             panicking: false,
+            stopped: false,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stopped {
+            (0, Some(0))
+        } else {
+            (0, Some(self.raw.len()))
         }
     }
 }
 
-impl<S, F> Drop for DrainFilter<'_, S, F>
-where
-    S: ?Sized + Storage,
-    F: FnMut(&mut S::Item) -> bool,
-{
+impl<S: ?Sized + Storage, D: Decide<S::Item>> Drop for Filter<'_, S, D> {
     fn drop(&mut self) {
         if !self.panicking {
             self.for_each(drop);
@@ -46,69 +84,216 @@ where
     }
 }
 
-impl<S, F> FusedIterator for DrainFilter<'_, S, F>
-where
-    S: ?Sized + Storage,
-    F: FnMut(&mut S::Item) -> bool,
-{
-}
-impl<S, F> Iterator for DrainFilter<'_, S, F>
-where
-    S: ?Sized + Storage,
-    F: FnMut(&mut S::Item) -> bool,
-{
-    type Item = S::Item;
+impl<S: ?Sized + Storage, D: Decide<S::Item>> Iterator for Filter<'_, S, D> {
+    type Item = Result<S::Item, D::Break>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if self.raw.is_empty() {
+            if self.stopped || self.raw.is_empty() {
                 break None
             }
 
             unsafe {
+                let index = self.raw.remaining_range().start;
                 let value = self.raw.front_mut();
 
                 let on_drop = SetOnDrop(&mut self.panicking);
-                let do_take = (self.filter)(value);
+                let do_take = self.decide.decide(index, value);
                 core::mem::forget(on_drop);
 
-                if do_take {
-                    break Some(self.raw.take_front())
+                match do_take {
+                    ControlFlow::Continue(true) => break Some(Ok(self.raw.take_front())),
+                    ControlFlow::Continue(false) => self.raw.skip_front(),
+                    ControlFlow::Break(value) => {
+                        self.stopped = true;
+                        break Some(Err(value))
+                    }
                 }
-                self.raw.skip_front();
             }
         }
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.raw.len();
-        (0, Some(len))
-    }
+    fn size_hint(&self) -> (usize, Option<usize>) { Filter::size_hint(self) }
 }
 
-impl<S, F> DoubleEndedIterator for DrainFilter<'_, S, F>
-where
-    S: ?Sized + Storage,
-    F: FnMut(&mut S::Item) -> bool,
-{
+impl<S: ?Sized + Storage, D: Decide<S::Item>> DoubleEndedIterator for Filter<'_, S, D> {
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
-            if self.raw.is_empty() {
+            if self.stopped || self.raw.is_empty() {
                 break None
             }
 
             unsafe {
+                let index = self.raw.remaining_range().end - 1;
                 let value = self.raw.back_mut();
 
                 let on_drop = SetOnDrop(&mut self.panicking);
-                let do_take = (self.filter)(value);
+                let do_take = self.decide.decide(index, value);
                 core::mem::forget(on_drop);
 
-                if do_take {
-                    break Some(self.raw.take_back())
+                match do_take {
+                    ControlFlow::Continue(true) => break Some(Ok(self.raw.take_back())),
+                    ControlFlow::Continue(false) => self.raw.skip_back(),
+                    ControlFlow::Break(value) => {
+                        self.stopped = true;
+                        break Some(Err(value))
+                    }
                 }
-                self.raw.skip_back();
             }
         }
     }
 }
+
+fn infallible<T>(item: Result<T, Infallible>) -> T {
+    match item {
+        Ok(item) => item,
+        Err(never) => match never {},
+    }
+}
+
+/// This struct is created by [`GenericVec::drain_filter`](crate::GenericVec::drain_filter).
+/// See its documentation for more.
+pub struct DrainFilter<'a, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> bool,
+{
+    inner: Filter<'a, S, ByValue<F>>,
+}
+
+impl<'a, S, F> DrainFilter<'a, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> bool,
+{
+    pub(crate) fn new(raw: RawCursor<'a, S>, filter: F) -> Self {
+        Self {
+            inner: Filter::new(raw, ByValue(filter)),
+        }
+    }
+}
+
+impl<S, F> FusedIterator for DrainFilter<'_, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> bool,
+{
+}
+
+impl<S, F> Iterator for DrainFilter<'_, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(infallible) }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<S, F> DoubleEndedIterator for DrainFilter<'_, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(infallible) }
+}
+
+/// This struct is created by [`GenericVec::try_drain_filter`](crate::GenericVec::try_drain_filter).
+/// See its documentation for more.
+pub struct TryDrainFilter<'a, S, F, B>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> ControlFlow<B, bool>,
+{
+    inner: Filter<'a, S, ByControlFlow<F>>,
+}
+
+impl<'a, S, F, B> TryDrainFilter<'a, S, F, B>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> ControlFlow<B, bool>,
+{
+    pub(crate) fn new(raw: RawCursor<'a, S>, filter: F) -> Self {
+        Self {
+            inner: Filter::new(raw, ByControlFlow(filter)),
+        }
+    }
+}
+
+impl<S, F, B> FusedIterator for TryDrainFilter<'_, S, F, B>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> ControlFlow<B, bool>,
+{
+}
+
+impl<S, F, B> Iterator for TryDrainFilter<'_, S, F, B>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> ControlFlow<B, bool>,
+{
+    type Item = Result<S::Item, B>;
+
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next() }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<S, F, B> DoubleEndedIterator for TryDrainFilter<'_, S, F, B>
+where
+    S: ?Sized + Storage,
+    F: FnMut(&mut S::Item) -> ControlFlow<B, bool>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back() }
+}
+
+/// This struct is created by [`GenericVec::drain_filter_indexed`](crate::GenericVec::drain_filter_indexed).
+/// See its documentation for more.
+pub struct DrainFilterIndexed<'a, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(usize, &mut S::Item) -> bool,
+{
+    inner: Filter<'a, S, ByIndex<F>>,
+}
+
+impl<'a, S, F> DrainFilterIndexed<'a, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(usize, &mut S::Item) -> bool,
+{
+    pub(crate) fn new(raw: RawCursor<'a, S>, filter: F) -> Self {
+        Self {
+            inner: Filter::new(raw, ByIndex(filter)),
+        }
+    }
+}
+
+impl<S, F> FusedIterator for DrainFilterIndexed<'_, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(usize, &mut S::Item) -> bool,
+{
+}
+
+impl<S, F> Iterator for DrainFilterIndexed<'_, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(usize, &mut S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(infallible) }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<S, F> DoubleEndedIterator for DrainFilterIndexed<'_, S, F>
+where
+    S: ?Sized + Storage,
+    F: FnMut(usize, &mut S::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(infallible) }
+}
@@ -31,8 +31,7 @@ impl<S: ?Sized + Storage> Drop for IntoIter<S> {
             let index = self.index;
 
             let drop_alloc = DropAlloc(&mut self.vec.storage);
-            let data = drop_alloc.0.as_mut().as_mut_ptr().add(index);
-            core::ptr::slice_from_raw_parts_mut(data, len.wrapping_sub(index)).drop_in_place();
+            drop_alloc.0.__drop_items(index..len);
         }
     }
 }
@@ -98,9 +97,11 @@ impl<S: ?Sized + Storage> Iterator for IntoIter<S> {
             None
         } else {
             unsafe {
-                let value = self.vec.get_unchecked(self.index);
+                let index = self.index;
+                let value = ptr::read(self.vec.get_unchecked_mut(index));
                 self.index += 1;
-                Some(ptr::read(value))
+                self.vec.storage.__release(index..index + 1);
+                Some(value)
             }
         }
     }
@@ -64,6 +64,8 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
 
     /// Skip all the remaining elements, and ensure that the [`GenericVec`] is
     /// valid
+    ///
+    /// Calling `finish` more than once has no further effect
     pub fn finish(&mut self) {
         unsafe {
             if self.old_vec_len == 0 {
@@ -89,12 +91,19 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
                     self.write_front.copy_from(self.write_back, back_len);
                 }
 
-                self.vec.as_mut().set_len_unchecked(len);
+                let vec = self.vec.as_mut();
+                vec.set_len_unchecked(len);
+                if len < self.old_vec_len {
+                    vec.storage.__release(len..self.old_vec_len);
+                }
             }
+
+            self.old_vec_len = 0;
         }
     }
 
     /// Check if the both write pointers are and the end of the vector
+    #[cfg(not(feature = "no-panic"))]
     pub(crate) fn at_back_of_vec(&self) -> bool {
         unsafe {
             let vec = self.vec.as_ref();
@@ -103,7 +112,37 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
         }
     }
 
+    /// A pointer to the last element that was kept in the vector, in front of the `RawCursor`
+    ///
+    /// # Safety
+    ///
+    /// There must be at least one element in the vector in front of the `RawCursor`
+    #[cfg(any(not(feature = "no-panic"), feature = "std"))]
+    pub(crate) unsafe fn last_kept_front(&self) -> *mut S::Item {
+        if Self::IS_ZS {
+            debug_assert!(self.write_front as usize != 0, "there are no elements in front of the `RawCursor`");
+            Self::ZS_PTR
+        } else {
+            // Safety: there is an element before `write_front`
+            unsafe { self.write_front.sub(1) }
+        }
+    }
+
+    /// The number of elements in the vector after the back of the `RawCursor`
+    #[cfg(not(feature = "no-panic"))]
+    pub(crate) fn tail_len(&self) -> usize {
+        if Self::IS_ZS {
+            self.old_vec_len.wrapping_sub(self.write_back as usize)
+        } else {
+            unsafe {
+                let end = self.vec.as_ref().as_ptr().add(self.old_vec_len);
+                end.offset_from(self.write_back) as usize
+            }
+        }
+    }
+
     /// Get a mutable reference to the underlying vector
+    #[cfg(not(feature = "no-panic"))]
     pub(crate) unsafe fn vec_mut(&mut self) -> &mut SimpleVec<S> { unsafe { self.vec.as_mut() } }
 
     /// The number of remaining elements in range of this `RawCursor`
@@ -118,6 +157,38 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
         }
     }
 
+    /// The indices that the remaining elements in range of this `RawCursor`
+    /// had in the vector, before the `RawCursor` was created
+    #[inline]
+    pub fn remaining_range(&self) -> Range<usize> {
+        if Self::IS_ZS {
+            self.read_front as usize..self.read_back as usize
+        } else {
+            unsafe {
+                let start = self.vec.as_ref().as_ptr();
+                let front = self.read_front.offset_from(start) as usize;
+                let back = self.read_back.offset_from(start) as usize;
+                front..back
+            }
+        }
+    }
+
+    /// The remaining elements in range of this `RawCursor`
+    #[inline]
+    pub fn as_slice(&self) -> &[S::Item] {
+        let ptr = if Self::IS_ZS { Self::ZS_PTR } else { self.read_front };
+        // Safety: the remaining elements are initialized
+        unsafe { core::slice::from_raw_parts(ptr, self.len()) }
+    }
+
+    /// The remaining elements in range of this `RawCursor`
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S::Item] {
+        let ptr = if Self::IS_ZS { Self::ZS_PTR } else { self.read_front };
+        // Safety: the remaining elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(ptr, self.len()) }
+    }
+
     /// Returns `true` if the `RawCursor` is empty
     #[inline]
     pub fn is_empty(&self) -> bool { self.read_back == self.read_front }
@@ -382,6 +453,25 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
         }
     }
 
+    /// Removes the next `n` elements of the `RawCursor` from the
+    /// underlying [`GenericVec`], without dropping them
+    ///
+    /// Advances the `RawCursor` by `n` elements
+    ///
+    /// # Safety
+    ///
+    /// * The `RawCursor`'s length must be at least equal to `n`
+    /// * The elements must have been moved out of the vector
+    pub(crate) unsafe fn forget_n_front(&mut self, n: usize) {
+        debug_assert!(self.len() >= n, "Cannot forget {} elements from a `RawCursor` of length {}", n, self.len());
+
+        if Self::IS_ZS {
+            self.read_front = (self.read_front as usize).wrapping_add(n) as _;
+        } else {
+            self.read_front = unsafe { self.read_front.add(n) };
+        }
+    }
+
     /// Drops the last `n` elements of the `RawCursor`
     /// and removes them from the underlying [`GenericVec`]
     ///
@@ -653,6 +743,7 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
     /// * Panics if the `RawCursor` is not empty
     /// * May panic if the underlying [`GenericVec`] cannot
     ///   reserve more space
+    #[cfg(not(feature = "no-panic"))]
     pub fn reserve(&mut self, space: usize) {
         assert!(self.is_empty(), "You can only call `reserve` on a empty `RawCursor`");
         unsafe {
@@ -695,6 +786,8 @@ impl<'a, S: ?Sized + Storage> RawCursor<'a, S> {
                 }
 
                 let increase_by = space.wrapping_sub(write_space);
+                let old_vec_len = self.old_vec_len;
+                self.vec.as_mut().storage.__acquire(old_vec_len..old_vec_len + increase_by);
                 let new_write_back = self.write_back.add(increase_by);
                 new_write_back.copy_from(self.write_back, back_len);
                 self.write_back = new_write_back;
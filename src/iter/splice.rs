@@ -36,42 +36,30 @@ impl<S: ?Sized + Storage, I: Iterator<Item = S::Item>> Drop for Splice<'_, S, I>
             }
         }
 
-        #[cfg(not(feature = "alloc"))]
-        {
-            const CAPACITY: usize = 16;
-
-            let mut buffer = crate::uninit_array::<_, CAPACITY>();
-            let mut buffer = crate::SliceVec::new(&mut buffer);
-
-            replace_with.for_each(|item| unsafe {
-                buffer.push_unchecked(item);
-
-                if buffer.is_full() {
-                    unsafe {
-                        raw.reserve(buffer.len());
-                        raw.write_slice_front(&buffer);
-                        buffer.set_len_unchecked(0);
-                    }
-                }
-            });
+        // move the tail once by the number of items that `replace_with` is
+        // known to yield, and write them directly into the gap
+        let (lower_bound, _) = replace_with.size_hint();
+
+        if lower_bound != 0 {
+            raw.reserve(lower_bound);
 
-            unsafe {
-                raw.reserve(buffer.len());
-                raw.write_slice_front(&buffer);
-                core::mem::forget(buffer);
+            while !raw.is_write_empty() {
+                match replace_with.next() {
+                    Some(value) => unsafe { raw.write_front(value) },
+                    None => return,
+                }
             }
         }
 
-        #[cfg(feature = "alloc")]
-        {
-            let mut temp: std::vec::Vec<S::Item> = replace_with.collect();
+        // `replace_with` yields more items than its size hint promised, so
+        // append them to the vector, and then rotate them in front of the tail
+        let tail_len = raw.tail_len();
+        raw.finish();
 
-            unsafe {
-                raw.reserve(temp.len());
-                raw.write_slice_front(&temp);
-                temp.set_len(0);
-            }
-        }
+        let vec = unsafe { raw.vec_mut() };
+        let start = vec.len() - tail_len;
+        vec.extend(replace_with);
+        vec[start..].rotate_left(tail_len);
     }
 }
 
@@ -34,7 +34,17 @@
 //! * `std` (default) - enables you to use an allocator, and
 //! * `alloc` - enables you to use an allocator, for heap allocated storages
 //!     (like [`Vec`])
-//! * `nightly` - enables you to use the Allocator trait
+//! * `nightly` - enables you to use the Allocator trait, and adds optimisations that need
+//!   specialization (like copying `Copy` items with a single `memcpy`). The array operations
+//!   (like [`GenericVec::push_array`]) work without it
+//! * `no-panic` - removes every method that panics or aborts when it runs out of capacity
+//!   (like [`GenericVec::push`] or [`GenericVec::reserve`]), along with the trait impls
+//!   and macros that use them, so only the fallible equivalents (like
+//!   [`GenericVec::try_push`] and [`GenericVec::try_reserve`]) are left.
+//!   Since this removes public API, it is not additive: only enable it in final binaries,
+//!   never in libraries. Mutable access to a shared buffer (like [`ArcVec`]) still has
+//!   to copy the buffer, and aborts if that isn't possible, so call
+//!   [`GenericVec::try_reserve`] first to handle that error
 //!
 //! # Basic Usage
 //!
@@ -43,6 +53,7 @@
 //! [`SliceVec`] stores an uninit slice buffer, and they store all of thier values in that buffer.
 //!
 //! ```rust
+//! # #[cfg(not(feature = "no-panic"))] {
 //! use cl_generic_vec::{SliceVec, uninit_array};
 //!
 //! let mut uninit_buffer = uninit_array::<_, 16>();
@@ -51,6 +62,7 @@
 //! assert!(slice_vec.is_empty());
 //! slice_vec.push(10);
 //! assert_eq!(slice_vec, [10]);
+//! # }
 //! ```
 //!
 //! Of course if you try to push past a `*SliceVec`'s capacity
@@ -64,6 +76,7 @@
 //! unlike the slice versions.
 //!
 //! ```rust
+//! # #[cfg(not(feature = "no-panic"))] {
 //! use cl_generic_vec::ArrayVec;
 //!
 //! let mut array_vec = ArrayVec::<i32, 16>::new();
@@ -73,6 +86,7 @@
 //! array_vec.push(30);
 //!
 //! assert_eq!(array_vec, [10, 20, 30]);
+//! # }
 //! ```
 //!
 //! ## `alloc`
@@ -82,6 +96,7 @@
 //! requries either the `alloc` or `std` feature to be enabled.
 //!
 //! ```rust
+//! # #[cfg(not(feature = "no-panic"))] {
 //! use cl_generic_vec::{HeapVec, gvec};
 //! let mut vec: HeapVec<u32> = gvec![1, 2, 3, 4];
 //! assert_eq!(vec.capacity(), 4);
@@ -90,6 +105,7 @@
 //! assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8]);
 //!
 //! vec.try_push(5).expect_err("Tried to push past capacity!");
+//! # }
 //! ```
 //!
 //! ## `nightly`
@@ -109,18 +125,27 @@ extern crate alloc as std;
 
 use core::{
     mem::{ManuallyDrop, MaybeUninit},
-    ops::{Deref, DerefMut, RangeBounds},
-    ptr,
+    ops::{ControlFlow, Deref, DerefMut, RangeBounds},
 };
 
+mod copy_vec;
 mod extension;
+mod heap_size;
 mod impls;
 mod slice;
+mod tail;
+mod template;
 
 pub mod iter;
 pub mod raw;
+#[cfg(all(feature = "testing", not(feature = "no-panic")))]
+#[cfg_attr(doc, doc(cfg(feature = "testing")))]
+pub mod testing;
 
 use raw::{AllocError, AllocResult, Storage};
+pub use copy_vec::CopyArrayVec;
+pub use heap_size::HeapSize;
+pub use tail::TailVec;
 
 #[doc(hidden)]
 pub use core;
@@ -143,13 +168,41 @@ pub type HeapVec<T, A = std::alloc::Global> = GenericVec<T, Box<[MaybeUninit<T>]
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 pub type HeapVec<T> = GenericVec<T, Box<[MaybeUninit<T>]>>;
 
+/// A heap backed vector that shares its buffer between clones, and copies it on the first write.
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{gvec, ArcVec, HeapVec};
+///
+/// let vec: HeapVec<i32> = gvec![1, 2, 3];
+/// let mut a = ArcVec::from(vec);
+/// let b = a.clone();
+/// assert!(a.storage().is_shared());
+///
+/// a.push(4);
+/// assert!(!a.storage().is_shared());
+/// assert_eq!(a, [1, 2, 3, 4]);
+/// assert_eq!(b, [1, 2, 3]);
+/// # }
+/// ```
+#[cfg(any(doc, feature = "alloc"))]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+pub type ArcVec<T> = GenericVec<T, raw::ArcStorage<T>>;
+
+/// A heap backed vector that shares its buffer between clones, and copies it on the first write.
+///
+/// This is the single threaded version of [`ArcVec`]
+#[cfg(any(doc, feature = "alloc"))]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+pub type RcVec<T> = GenericVec<T, raw::RcStorage<T>>;
+
 /// An array backed vector backed by potentially uninitialized memory
 pub type ArrayVec<T, const N: usize> = GenericVec<T, [MaybeUninit<T>; N]>;
 /// An slice backed vector backed by potentially uninitialized memory
 pub type SliceVec<'a, T> = GenericVec<T, &'a mut [MaybeUninit<T>]>;
 
 /// Creates a new uninit array, See [`MaybeUninit::uninit_array`]
-pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
     unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
 }
 
@@ -160,12 +213,29 @@ pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
 ///
 /// ```rust
 /// # use cl_generic_vec::{gvec, ArrayVec};
-/// let x: ArrayVec<i32, 2> = gvec![0, 1];
-/// assert_eq!(x, [0, 1]);
+/// let x: ArrayVec<i32, 4> = gvec![1, 2, 3, 4];
+/// assert_eq!(x, [1, 2, 3, 4]);
+/// ```
+///
+/// Prefixing the items with `const:` creates an [`ArrayVec`](type@ArrayVec)
+/// with [`ArrayVec::from_partial_array`], which works in `const` and `static` initializers
+///
+/// ```rust
+/// # use cl_generic_vec::{gvec, ArrayVec};
+/// static PRIMES: ArrayVec<u32, 8> = gvec![const: 2, 3, 5, 7];
+/// static ZEROS: ArrayVec<u32, 8> = gvec![const: 0; 3];
+/// assert_eq!(PRIMES, [2, 3, 5, 7]);
+/// assert_eq!(ZEROS, [0, 0, 0]);
 /// ```
 #[macro_export]
-#[cfg(feature = "nightly")]
+#[cfg(not(feature = "no-panic"))]
 macro_rules! gvec {
+    (const: $expr:expr; $n:expr) => {
+        $crate::ArrayVec::from_partial_array([$expr; $n])
+    };
+    (const: $($expr:expr),* $(,)?) => {
+        $crate::ArrayVec::from_partial_array([$($expr),*])
+    };
     ($expr:expr; $n:expr) => {{
         let len = $n;
         let mut vec = $crate::GenericVec::with_capacity(len);
@@ -182,48 +252,23 @@ macro_rules! gvec {
 
 #[doc(hidden)]
 #[macro_export]
+#[deprecated(note = "`gvec!` doesn't use this anymore, count the items with an array instead")]
 macro_rules! count {
     () => { 0 };
     ($($a:tt $b:tt)*) => { $crate::count!($($a)*) << 1 };
     ($c:tt $($a:tt $b:tt)*) => { ($crate::count!($($a)*) << 1) | 1 };
 }
 
-/// Create a new generic vector
-///
-/// Because this can create any generic vector, you will likely
-/// need to add some type annotations when you use it,
-///
-/// ```rust
-/// # use cl_generic_vec::{gvec, ArrayVec};
-/// let x: ArrayVec<i32, 4> = gvec![1, 2, 3, 4];
-/// assert_eq!(x, [1, 2, 3, 4]);
-/// ```
-#[macro_export]
-#[cfg(not(feature = "nightly"))]
-macro_rules! gvec {
-    ($expr:expr; $n:expr) => {{
-        let len = $n;
-        let mut vec = $crate::GenericVec::with_capacity(len);
-        vec.grow(len, $expr);
-        vec
-    }};
-    ($($expr:expr),*) => {{
-        let mut vec = $crate::GenericVec::with_capacity($crate::count!($(($expr))*));
-        unsafe {
-            $(vec.push_unchecked($expr);)*
-        }
-        vec
-    }};
-}
-
 /// Save the changes to [`GenericVec::spare_capacity_mut`]
 ///
 /// $orig - a mutable reference to a [`GenericVec`]
-/// $spare - the [`SliceVec`] that was obtained from [`$orig.spare_capacity_mut()`]
+/// $spare - the [`SliceVec`] that was created from `$orig.spare_capacity_mut()`
+///
+/// [`GenericVec::tail_vec`] is a safe alternative to this macro.
 ///
 /// # Safety
 ///
-/// `$spare` should be the [`SliceVec`] returned by `$orig.spare_capacity_mut()`
+/// `$spare` should be the [`SliceVec`] created from `$orig.spare_capacity_mut()`
 #[macro_export]
 macro_rules! save_spare {
     ($spare:expr, $orig:expr) => {{
@@ -295,7 +340,7 @@ impl<T, S: ?Sized + Storage<Item = T>> Drop for GenericVec<T, S> {
         // as part of the guarantee on `self.set_len_unchecked`
         // These elements should be dropped when the `GenericVec` gets dropped/
         // The storage will clean it's self up on drop
-        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+        unsafe { self.storage.__drop_items(0..self.len) }
     }
 }
 
@@ -306,43 +351,124 @@ impl<S: Storage> SimpleVec<S> {
     /// use cl_generic_vec::{ArrayVec, uninit_array};
     /// let vec = ArrayVec::<i32, 4>::with_storage(uninit_array());
     /// ```
-    pub fn with_storage(storage: S) -> Self {
+    pub const fn with_storage(storage: S) -> Self {
         Self::with_storage_len(storage, 0)
     }
 
-    fn with_storage_len(storage: S, len: usize) -> Self {
+    const fn with_storage_len(storage: S, len: usize) -> Self {
         Self { len, storage }
     }
 }
 
 impl<S: raw::StorageWithCapacity> SimpleVec<S> {
     /// Create a new empty `GenericVec` with the backend with at least the given capacity
+    #[cfg(not(feature = "no-panic"))]
     pub fn with_capacity(capacity: usize) -> Self {
         Self::with_storage(S::with_capacity(capacity))
     }
 
+    /// Try to create a new empty `GenericVec` with the backend with at least the given capacity
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if it's not possible to create a storage with enough space
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        S::try_with_capacity(capacity).map(Self::with_storage)
+    }
+
+    /// Try to clone the vector into a new vector with the same storage type
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<i32, 4>::from_array([1, 2, 3, 4]);
+    /// assert_eq!(vec.try_clone().ok(), Some(vec));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if it's not possible to create a storage with enough space
+    pub fn try_clone(&self) -> Result<Self, AllocError>
+    where
+        S::Item: Clone,
+    {
+        let mut vec = Self::try_with_capacity(self.len())?;
+        // Safety: the new vector has enough capacity for all the items
+        unsafe { vec.extend_from_slice_reserved(self) };
+        Ok(vec)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
+    #[cfg(not(feature = "no-panic"))]
     fn __with_capacity__const_capacity_checked(capacity: usize, old_capacity: Option<usize>) -> Self {
         Self::with_storage(S::__with_capacity__const_capacity_checked(capacity, old_capacity))
     }
 }
 
-unsafe fn tm_array<T, U, const N: usize>(array: [T; N]) -> [U; N] {
+/// Moves the elements that are still in place back over the elements that were moved out
+/// of the vector when dropped, and releases the slots that are no longer used
+struct RetainGuard<'a, S: ?Sized + Storage> {
+    vec: &'a mut SimpleVec<S>,
+    original_len: usize,
+    write: usize,
+    read: usize,
+}
+
+impl<S: ?Sized + Storage> Drop for RetainGuard<'_, S> {
+    fn drop(&mut self) {
+        let rest = self.original_len - self.read;
+        let len = self.write + rest;
+
+        // Safety
+        //
+        // * the elements in `read..original_len` are still in place
+        // * the elements in `write..read` were moved out of the vector
+        // * the elements in `..write` were kept
+        unsafe {
+            if self.write != self.read {
+                let ptr = self.vec.as_mut_ptr();
+                ptr.add(self.write).copy_from(ptr.add(self.read), rest);
+            }
+
+            self.vec.set_len_unchecked(len);
+        }
+
+        if len < self.original_len {
+            self.vec.storage.__release(len..self.original_len);
+        }
+    }
+}
+
+/// Releases the slots in the range when dropped
+struct ReleaseOnDrop<'a, S: ?Sized + Storage>(&'a mut S, core::ops::Range<usize>);
+
+impl<S: ?Sized + Storage> Drop for ReleaseOnDrop<'_, S> {
+    fn drop(&mut self) { self.0.__release(self.1.clone()) }
+}
+
+/// Releases the slots between the length of the vector and `end` when dropped
+struct ReleaseSpareOnDrop<'a, S: ?Sized + Storage> {
+    vec: &'a mut SimpleVec<S>,
+    end: usize,
+}
+
+impl<S: ?Sized + Storage> Drop for ReleaseSpareOnDrop<'_, S> {
+    fn drop(&mut self) {
+        let len = self.vec.len();
+        self.vec.storage.__release(len..self.end);
+    }
+}
+
+const unsafe fn tm_array<T, U, const N: usize>(array: [T; N]) -> [U; N] {
     let array = ManuallyDrop::new(array);
-    unsafe { array.as_ptr().cast::<[U; N]>().read() }
+    unsafe { core::ptr::addr_of!(array).cast::<[U; N]>().read() }
 }
 
 impl<T, const N: usize> ArrayVec<T, N> {
     /// Create a new empty `ArrayVec`
-    pub fn new() -> Self {
-        let uninit = MaybeUninit::<[T; N]>::uninit();
-        let uninit = unsafe { tm_array::<T, MaybeUninit<T>, N>(MaybeUninit::assume_init(uninit)) };
-        Self::with_storage(uninit)
-    }
+    pub const fn new() -> Self { Self::with_storage(uninit_array()) }
 
     /// Create a new full `ArrayVec`
-    pub fn from_array(array: [T; N]) -> Self {
+    pub const fn from_array(array: [T; N]) -> Self {
         // Safety:
         // The two arrays have exactly the same representation
         // and the code is taking ownership of the maybeuninit structure,
@@ -352,6 +478,227 @@ impl<T, const N: usize> ArrayVec<T, N> {
         Self { len: N, storage }
     }
 
+    /// Create a new `ArrayVec` that contains all the items of an array with at most `N` items
+    ///
+    /// This is the const version of [`gvec!`]
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// static TABLE: ArrayVec<u8, 4> = ArrayVec::from_partial_array([1, 2, 3]);
+    /// assert_eq!(TABLE, [1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array has more than `N` items
+    #[cfg(not(feature = "no-panic"))]
+    pub const fn from_partial_array<const M: usize>(array: [T; M]) -> Self {
+        let mut vec = Self::new();
+        vec.extend_from_array(array);
+        vec
+    }
+
+    /// Tries to create a new `ArrayVec` that contains all the items of an array
+    ///
+    /// This is the fallible version of [`ArrayVec::from_partial_array`]
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<u8, 4>::try_from_partial_array([1, 2, 3]).unwrap();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// assert!(ArrayVec::<u8, 2>::try_from_partial_array([1, 2, 3]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the `Err(array)` if the array has more than `N` items
+    pub const fn try_from_partial_array<const M: usize>(array: [T; M]) -> Result<Self, [T; M]> {
+        if M > N {
+            return Err(array)
+        }
+
+        let mut vec = Self::new();
+        // Safety: there is space for `M` items in an empty vector
+        unsafe { vec.extend_from_array_unchecked(array) };
+        Ok(vec)
+    }
+
+    /// Appends an element to the back of the `ArrayVec`, in const contexts
+    ///
+    /// This is the const version of [`GenericVec::push`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is full
+    #[cfg(not(feature = "no-panic"))]
+    pub const fn const_push(&mut self, value: T) -> &mut T {
+        assert!(self.len < N, "Tried to push to a full ArrayVec");
+
+        let len = self.len;
+        self.len += 1;
+        self.storage[len].write(value)
+    }
+
+    /// Tries to append an element to the back of the `ArrayVec`, in const contexts
+    ///
+    /// This is the const version of [`GenericVec::try_push`]
+    ///
+    /// # Errors
+    /// Returns the `Err(value)` if the vector is full
+    pub const fn const_try_push(&mut self, value: T) -> Result<&mut T, T> {
+        if self.len == N {
+            return Err(value)
+        }
+
+        let len = self.len;
+        self.len += 1;
+        Ok(self.storage[len].write(value))
+    }
+
+    /// Removes the last element from the `ArrayVec` and returns it, in const contexts
+    ///
+    /// This is the const version of [`GenericVec::try_pop`]
+    pub const fn const_pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None
+        }
+
+        self.len -= 1;
+        // Safety: the first `len` items of the vector are initialized
+        Some(unsafe { self.storage[self.len].assume_init_read() })
+    }
+
+    /// Moves all the items of the array to the back of the `ArrayVec`, in const contexts
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// const TABLE: ArrayVec<u8, 8> = {
+    ///     let mut vec = ArrayVec::new();
+    ///     vec.extend_from_array([1, 2, 3]);
+    ///     vec.const_push(4);
+    ///     vec
+    /// };
+    /// assert_eq!(TABLE.const_as_slice(), [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector doesn't have space for `M` more items
+    #[cfg(not(feature = "no-panic"))]
+    pub const fn extend_from_array<const M: usize>(&mut self, array: [T; M]) {
+        assert!(N - self.len >= M, "Tried to extend an ArrayVec past its capacity");
+
+        // Safety: we checked that there is space for `M` more items
+        unsafe { self.extend_from_array_unchecked(array) }
+    }
+
+    /// Tries to move all the items of the array to the back of the `ArrayVec`, in const contexts
+    ///
+    /// This is the fallible version of [`ArrayVec::extend_from_array`]
+    ///
+    /// # Errors
+    /// Returns the `Err(array)` if the vector doesn't have space for `M` more items,
+    /// then the vector is left unchanged
+    pub const fn try_extend_from_array<const M: usize>(&mut self, array: [T; M]) -> Result<(), [T; M]> {
+        if N - self.len < M {
+            return Err(array)
+        }
+
+        // Safety: we checked that there is space for `M` more items
+        unsafe { self.extend_from_array_unchecked(array) };
+        Ok(())
+    }
+
+    /// Moves all the items of the array to the back of the `ArrayVec`, in const contexts
+    ///
+    /// # Safety
+    ///
+    /// The vector must have space for `M` more items
+    pub const unsafe fn extend_from_array_unchecked<const M: usize>(&mut self, array: [T; M]) {
+        let array = ManuallyDrop::new(array);
+
+        // Safety
+        //
+        // * there is space for `M` more items after the first `len` items
+        // * the array was moved into a `ManuallyDrop`, so its items are only owned by the vector
+        unsafe {
+            let dest = self.storage.as_mut_ptr().add(self.len).cast::<T>();
+            core::ptr::copy_nonoverlapping(core::ptr::addr_of!(array).cast::<T>(), dest, M);
+        }
+
+        self.len += M;
+    }
+
+    /// Extracts a slice containing the entire `ArrayVec`, in const contexts
+    ///
+    /// This is the const version of [`GenericVec::as_slice`]
+    pub const fn const_as_slice(&self) -> &[T] {
+        // Safety: the first `len` items of the vector are initialized
+        unsafe { core::slice::from_raw_parts(self.storage.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Create a new full `ArrayVec`, where each item is the result of calling `f` with its index
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<usize, 4>::from_fn(|i| i * i);
+    /// assert_eq!(vec, [0, 1, 4, 9]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        let mut vec = Self::new();
+
+        for i in 0..N {
+            // Safety: the vector has space for `N` items
+            unsafe { vec.push_unchecked(f(i)) };
+        }
+
+        vec
+    }
+
+    /// Create a new full `ArrayVec`, where each item is the result of calling `f` with its index
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<u8, 4>::try_from_fn(|i| u8::try_from(i * 100));
+    /// assert!(vec.is_err());
+    /// let vec = ArrayVec::<u8, 2>::try_from_fn(|i| u8::try_from(i * 100));
+    /// assert_eq!(vec.unwrap(), [0, 100]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`, after dropping all the items created so far
+    pub fn try_from_fn<E, F: FnMut(usize) -> Result<T, E>>(mut f: F) -> Result<Self, E> {
+        let mut vec = Self::new();
+
+        for i in 0..N {
+            // Safety: the vector has space for `N` items
+            unsafe { vec.push_unchecked(f(i)?) };
+        }
+
+        Ok(vec)
+    }
+
+    /// Move the items into an `ArrayVec` with a different capacity
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<i32, 4>::try_from_partial_array([1, 2, 3]).unwrap();
+    /// let vec = vec.try_into_capacity::<2>().unwrap_err();
+    /// let vec = vec.try_into_capacity::<3>().unwrap();
+    /// assert_eq!(vec.into_array(), [1, 2, 3]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(self)` if there are more than `M` items
+    pub fn try_into_capacity<const M: usize>(self) -> Result<ArrayVec<T, M>, Self> { self.try_convert() }
+
     /// Convert this `ArrayVec` into an array
     ///
     /// # Panics
@@ -392,6 +739,87 @@ impl<T> HeapVec<T> {
             storage: Box::<[MaybeUninit<T>]>::default(),
         }
     }
+
+    /// Converts the vector into [`Box<[T]>`](Box), dropping any excess capacity
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "no-panic"))] {
+    /// use cl_generic_vec::HeapVec;
+    ///
+    /// let mut vec = HeapVec::with_capacity(10);
+    /// vec.extend([1, 2, 3]);
+    /// let slice: Box<[i32]> = vec.into_boxed_slice();
+    /// assert_eq!(*slice, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn into_boxed_slice(self) -> Box<[T]> { std::vec::Vec::from(self).into_boxed_slice() }
+
+    /// Consumes and leaks the vector, returning a mutable reference to the contents
+    ///
+    /// Like [`Vec::leak`], this doesn't drop any excess capacity, which is leaked as well
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "no-panic"))] {
+    /// use cl_generic_vec::{gvec, HeapVec};
+    ///
+    /// let vec: HeapVec<i32> = gvec![1, 2, 3];
+    /// let slice: &'static mut [i32] = vec.leak();
+    /// slice[0] = 4;
+    /// assert_eq!(slice, [4, 2, 3]);
+    /// # }
+    /// ```
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        let (len, storage) = self.into_raw_parts();
+        let storage = Box::leak(storage);
+        // Safety: the first `len` items of the vector are initialized
+        unsafe { slice_assume_init_mut(&mut storage[..len]) }
+    }
+
+    /// Creates a `HeapVec` directly from a pointer, a length, and a capacity,
+    /// like [`Vec::from_raw_parts`]
+    ///
+    /// This is named differently from [`GenericVec::from_raw_parts`], which
+    /// takes a length-storage pair.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as [`Vec::from_raw_parts`]
+    pub unsafe fn from_raw_vec_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
+        unsafe { std::vec::Vec::from_raw_parts(ptr, len, capacity) }.into()
+    }
+
+    /// Decomposes the vector into a pointer, a length, and a capacity,
+    /// which can be turned back into a vector with [`HeapVec::from_raw_vec_parts`]
+    /// or [`Vec::from_raw_parts`]
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "no-panic"))] {
+    /// use cl_generic_vec::{gvec, HeapVec};
+    ///
+    /// let vec: HeapVec<i32> = gvec![1, 2, 3];
+    /// let (ptr, len, capacity) = vec.into_raw_vec_parts();
+    /// let vec = unsafe { Vec::from_raw_parts(ptr, len, capacity) };
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn into_raw_vec_parts(self) -> (*mut T, usize, usize) {
+        let mut vec = ManuallyDrop::new(std::vec::Vec::from(self));
+        (vec.as_mut_ptr(), vec.len(), vec.capacity())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Clone> ArcVec<T> {
+    /// Create a new empty `ArcVec`
+    pub fn new() -> Self { Self::with_storage(raw::ArcStorage::new()) }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Clone> RcVec<T> {
+    /// Create a new empty `RcVec`
+    pub fn new() -> Self { Self::with_storage(raw::RcStorage::new()) }
 }
 
 #[cfg(any(doc, all(feature = "nightly", feature = "alloc")))]
@@ -401,6 +829,29 @@ impl<T, A: std::alloc::Allocator> HeapVec<T, A> {
     pub fn with_alloc(alloc: A) -> Self {
         Self::with_storage(Box::new_uninit_slice_in(0, alloc))
     }
+
+    /// Create a new empty `HeapVec` with the given allocator, like [`Vec::new_in`]
+    pub fn new_in(alloc: A) -> Self { Self::with_alloc(alloc) }
+
+    /// Create a new empty `HeapVec` with at least the given capacity in the given allocator,
+    /// like [`Vec::with_capacity_in`]
+    #[cfg(not(feature = "no-panic"))]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::with_storage(Box::new_uninit_slice_in(capacity, alloc))
+    }
+
+    /// Try to create a new empty `HeapVec` with at least the given capacity in the given allocator
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if the allocation fails
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, AllocError> {
+        Box::try_new_uninit_slice_in(capacity, alloc)
+            .map(Self::with_storage)
+            .map_err(|_| AllocError)
+    }
+
+    /// Returns a reference to the underlying allocator
+    pub fn allocator(&self) -> &A { Box::allocator(&self.storage) }
 }
 
 impl<'a, T> SliceVec<'a, T> {
@@ -418,6 +869,30 @@ impl<'a, T> SliceVec<'a, T> {
         let storage = unsafe { &mut *(slice as *mut [T] as *mut [MaybeUninit<T>]) };
         Self::with_storage_len(storage, len)
     }
+
+    /// Splits the `SliceVec` into its items and its spare capacity
+    ///
+    /// The items are not dropped, they are left in the backing slice.
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "no-panic"))] {
+    /// use cl_generic_vec::{SliceVec, uninit_array};
+    ///
+    /// let mut buffer = uninit_array::<_, 4>();
+    /// let mut vec = unsafe { SliceVec::new(&mut buffer) };
+    /// vec.extend([1, 2, 3]);
+    ///
+    /// let (items, spare) = vec.into_parts();
+    /// assert_eq!(items, [1, 2, 3]);
+    /// assert_eq!(spare.len(), 1);
+    /// # }
+    /// ```
+    pub fn into_parts(self) -> (&'a mut [T], &'a mut [MaybeUninit<T>]) {
+        let (len, storage) = self.into_raw_parts();
+        let (items, spare) = storage.split_at_mut(len);
+        // Safety: the first `len` items of the vector are initialized
+        (unsafe { slice_assume_init_mut(items) }, spare)
+    }
 }
 
 impl<S: Storage> SimpleVec<S> {
@@ -437,28 +912,38 @@ impl<S: Storage> SimpleVec<S> {
     /// # Panic
     ///
     /// If the given storage cannot hold type `T`, then this method will panic
-    #[cfg(not(feature = "nightly"))]
-    pub unsafe fn from_raw_parts(len: usize, storage: S) -> Self {
+    pub const unsafe fn from_raw_parts(len: usize, storage: S) -> Self {
         Self { len, storage }
     }
 }
 
-#[cfg(feature = "nightly")]
-impl<S: Storage> SimpleVec<S> {
-    /// Create a `GenericVec` from a length-storage pair
+impl<T, const N: usize, S: raw::FlattenStorage<T, N>> GenericVec<[T; N], S> {
+    /// Takes a `GenericVec<[T; N], S>` and flattens it into a vector of `T`,
+    /// without moving the items
     ///
-    /// Note: this is only const with the `nightly` feature enabled
+    /// ```rust
+    /// # #[cfg(not(feature = "no-panic"))] {
+    /// use cl_generic_vec::HeapVec;
     ///
-    /// # Safety
+    /// let mut vec = HeapVec::new();
+    /// vec.extend([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
     ///
-    /// the length must be less than `raw.capacity()` and
-    /// all elements in the range `0..length`, must be initialized
+    /// let mut flattened = vec.into_flattened();
+    /// assert_eq!(flattened.pop(), 9);
+    /// assert_eq!(flattened, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// # }
+    /// ```
     ///
-    /// # Panic
+    /// # Panics
     ///
-    /// If the given storage cannot hold type `T`, then this method will panic
-    pub const unsafe fn from_raw_parts(len: usize, storage: S) -> Self {
-        Self { len, storage }
+    /// Panics if the length of the resulting vector would overflow a `usize`.
+    /// This is only possible when flattening a vector of arrays of zero-sized types.
+    pub fn into_flattened(self) -> GenericVec<T, S::Flattened> {
+        let (len, storage) = self.into_raw_parts();
+        let len = len.checked_mul(N).expect("vec len overflow");
+        // Safety: the first `len * N` items of the flattened storage are
+        // exactly the items of the first `len` arrays
+        unsafe { GenericVec::from_raw_parts(len, storage.flatten()) }
     }
 }
 
@@ -495,6 +980,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// * The elements at `old_len..new_len` must be initialized.
     pub unsafe fn set_len_unchecked(&mut self, len: usize) {
         self.len = len;
+        self.storage.__set_len(len);
     }
 
     /// Set the length of a vector
@@ -548,26 +1034,20 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     }
 
     /// Returns the remaining spare capacity of the vector as
-    /// a [`SliceVec<'_, T>`](SliceVec).
+    /// a slice of `MaybeUninit<T>`.
     ///
-    /// Keep in mind that the [`SliceVec<'_, T>`](SliceVec) will drop all elements
-    /// that you push into it when it goes out of scope! If you want
-    /// these modifications to persist then you should use [`save_spare`]
-    /// to persist these writes.
+    /// The returned slice can be used to fill the vector with data
+    /// (e.g. by reading from a file) before marking the data as initialized
+    /// using [`GenericVec::set_len`]. If you want to push into the spare
+    /// capacity instead, use [`GenericVec::tail_vec`].
     ///
     /// ```
     /// let mut vec = cl_generic_vec::ArrayVec::<i32, 16>::new();
     ///
-    /// let mut spare = vec.spare_capacity_mut();
-    /// spare.push(0);
-    /// spare.push(2);
-    /// drop(spare);
-    /// assert_eq!(vec, []);
-    ///
-    /// let mut spare = vec.spare_capacity_mut();
-    /// spare.push(0);
-    /// spare.push(2);
-    /// unsafe { cl_generic_vec::save_spare!(spare, &mut vec) }
+    /// let spare = vec.spare_capacity_mut();
+    /// spare[0].write(0);
+    /// spare[1].write(2);
+    /// unsafe { vec.set_len(2) }
     /// assert_eq!(vec, [0, 2]);
     /// ```
     pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<S::Item>] {
@@ -575,15 +1055,182 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         &mut self.storage.as_mut()[len..]
     }
 
-    /// Reserve enough space for at least `additional` elements
+    /// Returns the contents of the vector, and the remaining spare capacity
+    /// of the vector as a slice of `MaybeUninit<T>`.
     ///
-    /// # Panics
+    /// This is like [`GenericVec::spare_capacity_mut`], but it also gives access to
+    /// the items of the vector at the same time, for example to decode them in place.
     ///
-    /// May panic or abort if it isn't possible to allocate enough space for
-    /// `additional` more elements
-    #[inline]
-    pub fn reserve(&mut self, additional: usize) {
-        #[cold]
+    /// ```
+    /// let mut vec = cl_generic_vec::ArrayVec::<i32, 16>::try_from_partial_array([1, 2]).unwrap();
+    ///
+    /// let (items, spare) = vec.split_at_spare_mut();
+    /// spare[0].write(items[0] + items[1]);
+    /// items[0] = 0;
+    /// unsafe { vec.set_len(3) }
+    /// assert_eq!(vec, [0, 2, 3]);
+    /// ```
+    pub fn split_at_spare_mut(&mut self) -> (&mut [S::Item], &mut [MaybeUninit<S::Item>]) {
+        let len = self.len();
+        let capacity = self.capacity();
+        let ptr = self.storage.as_mut().as_mut_ptr();
+
+        // Safety
+        //
+        // * the first `len` items of the vector are initialized
+        // * the two slices don't overlap, and are both inside the storage
+        //   (or are zero-sized)
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(ptr.cast::<S::Item>(), len),
+                core::slice::from_raw_parts_mut(ptr.add(len), capacity - len),
+            )
+        }
+    }
+
+    /// Returns the remaining spare capacity of the vector as a growable
+    /// [`SliceVec<'_, T>`](SliceVec), behind a guard.
+    ///
+    /// All elements pushed into the [`SliceVec`] are added to the end of the vector
+    /// when the guard is dropped, even if it is dropped during a panic. This
+    /// allows passing a `&mut SliceVec<T>` to code that only knows about [`SliceVec`],
+    /// while the elements end up in this vector.
+    ///
+    /// The [`SliceVec`] can only hold as many elements as the vector's
+    /// remaining capacity, so you may want to [`reserve`](GenericVec::reserve) first.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic"))] {
+    /// use cl_generic_vec::{ArrayVec, SliceVec};
+    ///
+    /// fn fill(out: &mut SliceVec<i32>) {
+    ///     out.push(2);
+    ///     out.push(3);
+    /// }
+    ///
+    /// let mut vec = ArrayVec::<i32, 16>::new();
+    /// vec.push(1);
+    /// fill(&mut vec.tail_vec());
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// # }
+    /// ```
+    ///
+    /// If the [`SliceVec`] is replaced (for example with [`core::mem::swap`]), then
+    /// the replacement's elements are dropped, and the vector is left unchanged.
+    /// The original [`SliceVec`] keeps its elements, so the vector's spare capacity
+    /// isn't touched either, and a [`Poison`](raw::Poison) storage that verifies
+    /// its poison will report those slots as written to.
+    pub fn tail_vec(&mut self) -> TailVec<'_, S> { TailVec::new(self) }
+
+    /// Splits the spare capacity of the vector into `N` independent [`SliceVec`]s
+    /// of the given capacities, and passes them to `f`.
+    ///
+    /// Once `f` returns, the elements pushed into each part are moved back into the
+    /// vector in order, closing any gaps between the parts, and the length of the vector
+    /// grows by the total number of elements written.
+    ///
+    /// Because the parts are independent, they can be filled concurrently.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::new();
+    /// vec.push(0);
+    ///
+    /// vec.split_spare([3, 4], |[header, body]| {
+    ///     std::thread::scope(|s| {
+    ///         s.spawn(|| header.extend([1, 2]));
+    ///         s.spawn(|| body.extend([3, 4, 5]));
+    ///     });
+    /// });
+    ///
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// If a part is replaced by a [`SliceVec`] that was not created by this method,
+    /// then the replacement's elements are dropped instead of being moved into the vector.
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if the collection does not have enough spare capacity
+    /// for the sum of `lens`
+    ///
+    /// # Panic behavor
+    ///
+    /// If `f` panics, then all added items will be dropped, and the vector is left unchanged.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn split_spare<F, R, const N: usize>(&mut self, lens: [usize; N], f: F) -> R
+    where
+        F: for<'s> FnOnce(&mut [SliceVec<'s, S::Item>; N]) -> R,
+    {
+        let total = lens
+            .iter()
+            .try_fold(0_usize, |total, &len| total.checked_add(len))
+            .expect("Tried to split off more spare capacity than can be addressed");
+        self.reserve(total);
+        self.acquire_spare(total);
+
+        let mut rest = self.spare_capacity_mut();
+        let mut parts = core::array::from_fn::<_, N, _>(|i| {
+            let (part, tail) = core::mem::take(&mut rest).split_at_mut(lens[i]);
+            rest = tail;
+            // Safety: the spare capacity is uninitialized
+            unsafe { SliceVec::new(part) }
+        });
+
+        let starts = parts.each_ref().map(|part| part.as_ptr());
+
+        let output = f(&mut parts);
+
+        // Safety
+        //
+        // * parts that are still backed by their original region of the spare capacity
+        //   hold `part.len()` initialized elements at the start of that region
+        // * those elements are moved (in order) towards the front of the spare capacity,
+        //   which never overwrites a region that has not been moved yet
+        // * parts that were replaced by some other `SliceVec` are dropped before anything
+        //   is moved, so they are never observed after their region is overwritten
+        unsafe {
+            let parts = parts.map(|part| {
+                let part = ManuallyDrop::new(part);
+                (part.as_ptr(), part.len(), part.storage().len())
+            });
+
+            let mut is_owned = [false; N];
+            for (i, &(ptr, len, capacity)) in parts.iter().enumerate() {
+                is_owned[i] = ptr == starts[i] && capacity == lens[i];
+                if !is_owned[i] {
+                    core::ptr::slice_from_raw_parts_mut(ptr.cast_mut(), len).drop_in_place();
+                }
+            }
+
+            let len = self.len();
+            let mut written = 0;
+            let write = self.as_mut_ptr().add(len);
+            for (&(ptr, part_len, _), is_owned) in parts.iter().zip(is_owned) {
+                if is_owned {
+                    write.add(written).copy_from(ptr, part_len);
+                    written += part_len;
+                }
+            }
+
+            self.set_len_unchecked(len + written);
+            self.storage.__release(len + written..len + total);
+        }
+
+        output
+    }
+
+    /// Reserve enough space for at least `additional` elements
+    ///
+    /// # Panics
+    ///
+    /// May panic or abort if it isn't possible to allocate enough space for
+    /// `additional` more elements
+    #[inline]
+    #[cfg(not(feature = "no-panic"))]
+    pub fn reserve(&mut self, additional: usize) {
+        #[cold]
         #[inline(never)]
         fn allocation_failure(additional: usize) -> ! {
             panic!("Tried to allocate: {} more space and failed", additional)
@@ -603,6 +1250,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// Returns `Err(_)` if it's not possible to reserve enough space
     #[inline]
     pub fn try_reserve(&mut self, additional: usize) -> AllocResult {
+        self.storage.__try_make_mut()?;
         if self.remaining_capacity() < additional {
             match self.len().checked_add(additional) {
                 Some(new_capacity) => self.storage.try_reserve(new_capacity),
@@ -613,6 +1261,24 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         }
     }
 
+    /// Notifies the storage that the next `additional` slots of the spare capacity are about to be written
+    #[inline]
+    fn acquire_spare(&mut self, additional: usize) {
+        let len = self.len();
+        self.storage.__acquire(len..len.saturating_add(additional));
+    }
+
+    /// Acquires the next `additional` slots of the spare capacity, and passes the vector to `fill`
+    /// to write them. If `fill` panics, then the slots that it didn't add to the vector are released
+    #[inline]
+    fn fill_spare(&mut self, additional: usize, fill: impl FnOnce(&mut Self)) {
+        let end = self.len().saturating_add(additional);
+        self.acquire_spare(additional);
+        let release = ReleaseSpareOnDrop { vec: self, end };
+        fill(release.vec);
+        core::mem::forget(release);
+    }
+
     /// Shortens the vector, keeping the first len elements and dropping the rest.
     ///
     /// If len is greater than the vector's current length, this has no effect.
@@ -626,11 +1292,12 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             //   all the elements must be initialized
             // * the elements from `len..self.len()` are valid,
             //   and should be dropped
+            // * the slots are released even if dropping one of the elements panics
             unsafe {
                 self.set_len_unchecked(len);
-                let ptr = self.as_mut_ptr().add(len);
-                let len = diff;
-                core::ptr::drop_in_place(core::slice::from_raw_parts_mut(ptr, len));
+                let release = ReleaseOnDrop(&mut self.storage, len..len + diff);
+                let ptr = release.0.as_mut().as_mut_ptr().cast::<S::Item>().add(len);
+                core::ptr::drop_in_place(core::slice::from_raw_parts_mut(ptr, diff));
             }
         }
     }
@@ -650,6 +1317,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// If `T::clone` panics, then all added items will be dropped. This is different
     /// from `std`, where on panic, items will stay in the `Vec`. This behavior
     /// is unstable, and may change in the future.
+    #[cfg(not(feature = "no-panic"))]
     pub fn grow(&mut self, additional: usize, value: S::Item)
     where
         S::Item: Clone,
@@ -658,7 +1326,42 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         // # Safety
         //
         // * we reserved enough space
-        unsafe { extension::Extension::grow(self, additional, value) }
+        unsafe { self.grow_reserved(additional, value) }
+    }
+
+    /// Tries to grow the `GenericVec` in-place by additional elements.
+    ///
+    /// This is the fallible version of [`GenericVec::grow`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(value)` if there isn't enough space for `additional` more elements,
+    /// and it's not possible to reserve more space. Then the vector is left unchanged.
+    pub fn try_grow(&mut self, additional: usize, value: S::Item) -> Result<(), S::Item>
+    where
+        S::Item: Clone,
+    {
+        if self.try_reserve(additional).is_err() {
+            return Err(value)
+        }
+
+        // # Safety
+        //
+        // * we reserved enough space
+        unsafe { self.grow_reserved(additional, value) }
+        Ok(())
+    }
+
+    /// Grows the `GenericVec` by `additional` clones of `value`, without reserving
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `additional` remaining capacity in the vector
+    unsafe fn grow_reserved(&mut self, additional: usize, value: S::Item)
+    where
+        S::Item: Clone,
+    {
+        self.fill_spare(additional, |vec| unsafe { extension::Extension::grow(vec, additional, value) });
     }
 
     /// Grows the `GenericVec` in-place by additional elements.
@@ -677,33 +1380,70 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// If `F` panics, then all added items will be dropped. This is different
     /// from `std`, where on panic, items will stay in the `Vec`. This behavior
     /// is unstable, and may change in the future.
-    pub fn grow_with<F>(&mut self, additional: usize, mut value: F)
+    #[cfg(not(feature = "no-panic"))]
+    pub fn grow_with<F>(&mut self, additional: usize, value: F)
+    where
+        F: FnMut() -> S::Item,
+    {
+        self.reserve(additional);
+        // Safety: we reserved enough space
+        unsafe { self.grow_with_reserved(additional, value) }
+    }
+
+    /// Tries to grow the `GenericVec` in-place by additional elements.
+    ///
+    /// This is the fallible version of [`GenericVec::grow_with`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(value)` if there isn't enough space for `additional` more elements,
+    /// and it's not possible to reserve more space. Then the vector is left unchanged.
+    pub fn try_grow_with<F>(&mut self, additional: usize, value: F) -> Result<(), F>
+    where
+        F: FnMut() -> S::Item,
+    {
+        if self.try_reserve(additional).is_err() {
+            return Err(value)
+        }
+
+        // Safety: we reserved enough space
+        unsafe { self.grow_with_reserved(additional, value) }
+        Ok(())
+    }
+
+    /// Grows the `GenericVec` by `additional` elements created by `value`, without reserving
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `additional` remaining capacity in the vector
+    unsafe fn grow_with_reserved<F>(&mut self, additional: usize, mut value: F)
     where
         F: FnMut() -> S::Item,
     {
         // Safety
         //
-        // * we reserve enough space for `additional` elements
+        // * the caller reserved enough space for `additional` elements
         // * we use `spare_capacity_mut` to ensure that the items are dropped,
         //   even on panic
         // * the `ptr` always stays in bounds
 
-        self.reserve(additional);
-        let spare = self.spare_capacity_mut();
-        let mut writer = unsafe { SliceVec::new(spare) };
+        self.fill_spare(additional, |vec| {
+            let spare = vec.spare_capacity_mut();
+            let mut writer = unsafe { SliceVec::new(spare) };
 
-        for _ in 0..additional {
-            unsafe {
-                writer.push_unchecked(value());
+            for _ in 0..additional {
+                unsafe {
+                    writer.push_unchecked(value());
+                }
             }
-        }
 
-        unsafe {
-            // don't drop the new data!
-            let writer = core::mem::ManuallyDrop::new(writer);
-            let len = writer.len() + self.len();
-            self.set_len_unchecked(len);
-        }
+            unsafe {
+                // don't drop the new data!
+                let writer = core::mem::ManuallyDrop::new(writer);
+                let len = writer.len() + vec.len();
+                vec.set_len_unchecked(len);
+            }
+        });
     }
 
     /// Resizes the [`GenericVec`] in-place so that `len` is equal to `new_len`.
@@ -729,6 +1469,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// If `F` panics, then all added items will be dropped. This is different
     /// from `std`, where on panic, items will stay in the `Vec`. This behavior
     /// is unstable, and may change in the future.
+    #[cfg(not(feature = "no-panic"))]
     pub fn resize(&mut self, new_len: usize, value: S::Item)
     where
         S::Item: Clone,
@@ -767,6 +1508,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// If `F` panics, then all added items will be dropped. This is different
     /// from `std`, where on panic, items will stay in the `Vec`. This behavior
     /// is unstable, and may change in the future.
+    #[cfg(not(feature = "no-panic"))]
     pub fn resize_with<F: FnMut() -> S::Item>(&mut self, new_len: usize, value: F) {
         match new_len.checked_sub(self.len()) {
             Some(0) => (),
@@ -775,6 +1517,56 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         }
     }
 
+    /// Tries to resize the [`GenericVec`] in-place so that `len` is equal to `new_len`.
+    ///
+    /// This is the fallible version of [`GenericVec::resize`]
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 4>::new();
+    /// assert_eq!(vec.try_resize(3, 1), Ok(()));
+    /// assert_eq!(vec.try_resize(5, 2), Err(2));
+    /// assert_eq!(vec, [1, 1, 1]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(value)` if `new_len` is greater than `len`, and it's not possible
+    /// to reserve enough space. Then the vector is left unchanged.
+    pub fn try_resize(&mut self, new_len: usize, value: S::Item) -> Result<(), S::Item>
+    where
+        S::Item: Clone,
+    {
+        match new_len.checked_sub(self.len()) {
+            Some(0) => Ok(()),
+            Some(additional) => self.try_grow(additional, value),
+            None => {
+                self.truncate(new_len);
+                Ok(())
+            }
+        }
+    }
+
+    /// Tries to resize the [`GenericVec`] in-place so that `len` is equal to `new_len`.
+    ///
+    /// This is the fallible version of [`GenericVec::resize_with`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(value)` if `new_len` is greater than `len`, and it's not possible
+    /// to reserve enough space. Then the vector is left unchanged.
+    pub fn try_resize_with<F: FnMut() -> S::Item>(&mut self, new_len: usize, value: F) -> Result<(), F> {
+        match new_len.checked_sub(self.len()) {
+            Some(0) => Ok(()),
+            Some(additional) => self.try_grow_with(additional, value),
+            None => {
+                self.truncate(new_len);
+                Ok(())
+            }
+        }
+    }
+
     /// Clears the vector, removing all values.
     ///
     /// Note that this method has no effect on the allocated capacity of the vector.
@@ -787,6 +1579,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Panic
     ///
     /// May panic or reallocate if the collection is full
+    #[cfg(not(feature = "no-panic"))]
     pub fn push(&mut self, value: S::Item) -> &mut S::Item {
         if self.len() == self.capacity() {
             self.reserve(1);
@@ -803,7 +1596,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Panic
     ///
     /// May panic or reallocate if the collection has less than N elements remaining
-    #[cfg(any(doc, feature = "nightly"))]
+    #[cfg(not(feature = "no-panic"))]
     pub fn push_array<const N: usize>(&mut self, value: [S::Item; N]) -> &mut [S::Item; N] {
         self.reserve(N);
 
@@ -820,11 +1613,12 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     ///
     /// * May panic or reallocate if the collection is full
     /// * Panics if index > len.
+    #[cfg(not(feature = "no-panic"))]
     pub fn insert(&mut self, index: usize, value: S::Item) -> &mut S::Item {
         #[cold]
         #[inline(never)]
         fn insert_fail(index: usize, len: usize) -> ! {
-            panic!("Tried to insert at {}, but length is {}", index, len);
+            panic!("Tried to insert at {index}, but length is {len}");
         }
 
         if index > self.len() {
@@ -842,6 +1636,20 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         unsafe { self.insert_unchecked(index, value) }
     }
 
+    /// Clones and inserts all elements in a slice at position index within the vector, without reserving
+    ///
+    /// # Safety
+    ///
+    /// * `index` must be in bounds
+    /// * There must be at least `slice.len()` remaining capacity in the vector
+    unsafe fn insert_slice_reserved(&mut self, index: usize, slice: &[S::Item])
+    where
+        S::Item: Clone,
+    {
+        unsafe { self.extend_from_slice_reserved(slice) };
+        self.as_mut_slice()[index..].rotate_right(slice.len());
+    }
+
     /// Inserts the array at position index within the vector,
     /// shifting all elements after it to the right.
     ///
@@ -849,15 +1657,12 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     ///
     /// * May panic or reallocate if the collection has less than N elements remaining
     /// * Panics if index > len.
-    #[cfg(any(doc, feature = "nightly"))]
+    #[cfg(not(feature = "no-panic"))]
     pub fn insert_array<const N: usize>(&mut self, index: usize, value: [S::Item; N]) -> &mut [S::Item; N] {
         #[cold]
         #[inline(never)]
         fn insert_array_fail(index: usize, size: usize, len: usize) -> ! {
-            panic!(
-                "Tried to insert array of length {} at {}, but length is {}",
-                size, index, len
-            );
+            panic!("Tried to insert array of length {size} at {index}, but length is {len}");
         }
 
         if index > self.len() {
@@ -900,12 +1705,11 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Panics
     ///
     /// Panics if the collection contains less than `N` elements in it
-    #[cfg(any(doc, feature = "nightly"))]
     pub fn pop_array<const N: usize>(&mut self) -> [S::Item; N] {
         #[cold]
         #[inline(never)]
         fn pop_array_fail(size: usize, len: usize) -> ! {
-            panic!("Tried to pop an array of size {}, a vector of length {}", size, len);
+            panic!("Tried to pop an array of size {size}, a vector of length {len}");
         }
 
         if self.len() < N {
@@ -931,7 +1735,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             panic!("Tried to remove an element at {}, but length is {}", index, len);
         }
 
-        if index > self.len() {
+        if index >= self.len() {
             remove_fail(index, self.len())
         }
 
@@ -947,15 +1751,11 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Panics
     ///
     /// Panics if `index` is out of bounds or if `index + N > len()`
-    #[cfg(any(doc, feature = "nightly"))]
     pub fn remove_array<const N: usize>(&mut self, index: usize) -> [S::Item; N] {
         #[cold]
         #[inline(never)]
         fn remove_array_fail(index: usize, size: usize, len: usize) -> ! {
-            panic!(
-                "Tried to remove an array length {} at {}, but length is {}",
-                size, index, len
-            );
+            panic!("Tried to remove an array length {size} at {index}, but length is {len}");
         }
 
         if self.len() < index || self.len().wrapping_sub(index) < N {
@@ -986,7 +1786,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             panic!("Tried to remove an element at {}, but length is {}", index, len);
         }
 
-        if index > self.len() {
+        if index >= self.len() {
             swap_remove_fail(index, self.len())
         }
 
@@ -999,11 +1799,13 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// Tries to append an element to the back of a collection.
     ///
     /// # Errors
-    /// Returns the `Err(value)` if the collection is full
+    /// Returns the `Err(value)` if the collection is full,
+    /// or if a shared buffer can't be copied
     ///
-    /// Guaranteed to not panic/abort/allocate
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_push(&mut self, value: S::Item) -> Result<&mut S::Item, S::Item> {
-        if self.is_full() {
+        if self.storage.__try_make_mut().is_err() || self.is_full() {
             Err(value)
         } else {
             // Safety
@@ -1013,14 +1815,29 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         }
     }
 
+    /// Appends an element to the back of a collection if there is enough capacity,
+    /// like [`Vec::push_within_capacity`]
+    ///
+    /// This is the same as [`GenericVec::try_push`]
+    ///
+    /// # Errors
+    /// Returns the `Err(value)` if the collection is full,
+    /// or if a shared buffer can't be copied
+    ///
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
+    pub fn push_within_capacity(&mut self, value: S::Item) -> Result<&mut S::Item, S::Item> { self.try_push(value) }
+
     /// Tries to append an array to the back of a collection.
+    ///
+    /// # Errors
     /// Returns the `Err(value)` if the collection doesn't have enough remaining capacity
     /// to hold `N` elements.
     ///
-    /// Guaranteed to not panic/abort/allocate
-    #[cfg(any(doc, feature = "nightly"))]
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_push_array<const N: usize>(&mut self, value: [S::Item; N]) -> Result<&mut [S::Item; N], [S::Item; N]> {
-        if self.remaining_capacity() < N {
+        if self.storage.__try_make_mut().is_err() || self.remaining_capacity() < N {
             Err(value)
         } else {
             // Safety
@@ -1034,11 +1851,13 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// shifting all elements after it to the right.
     ///
     /// # Errors
-    /// Returns the `Err(value)` if the collection is full or index is out of bounds
+    /// Returns the `Err(value)` if the collection is full or index is out of bounds,
+    /// or if a shared buffer can't be copied
     ///
-    /// Guaranteed to not panic/abort/allocate
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_insert(&mut self, index: usize, value: S::Item) -> Result<&mut S::Item, S::Item> {
-        if self.is_full() || index > self.len() {
+        if self.storage.__try_make_mut().is_err() || self.is_full() || index > self.len() {
             Err(value)
         } else {
             // Safety
@@ -1049,19 +1868,61 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         }
     }
 
+    /// Tries to clone and insert all elements in a slice at position index within the vector,
+    /// shifting all elements after them to the right.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 4>::from_array([1, 2, 3, 4]);
+    /// vec.truncate(2);
+    /// assert!(vec.try_insert_slice(1, &[5, 6]).is_ok());
+    /// assert_eq!(vec, [1, 5, 6, 2]);
+    /// assert!(vec.try_insert_slice(1, &[7]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if index is out of bounds, or if there isn't enough space for
+    /// the slice, and it's not possible to reserve more space. Then the vector is
+    /// left unchanged.
+    ///
+    /// # Panic behavor
+    ///
+    /// If `T::clone` panics, then all newly added items will be dropped, and the
+    /// vector is left unchanged.
+    pub fn try_insert_slice(&mut self, index: usize, slice: &[S::Item]) -> AllocResult
+    where
+        S::Item: Clone,
+    {
+        if index > self.len() {
+            return Err(AllocError)
+        }
+
+        self.try_reserve(slice.len())?;
+
+        // Safety
+        //
+        // * we reserve enough space for the slice
+        // * we verify that index is in bounds
+        unsafe { self.insert_slice_reserved(index, slice) }
+        Ok(())
+    }
+
     /// Inserts an array at position index within the vector,
     /// shifting all elements after it to the right.
+    ///
+    /// # Errors
     /// Returns the `Err(value)` if the collection doesn't have enough remaining capacity
     /// to hold `N` elements or index is out of bounds
     ///
-    /// Guaranteed to not panic/abort/allocate
-    #[cfg(any(doc, feature = "nightly"))]
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_insert_array<const N: usize>(
         &mut self,
         index: usize,
         value: [S::Item; N],
     ) -> Result<&mut [S::Item; N], [S::Item; N]> {
-        if self.capacity().wrapping_sub(self.len()) < N || index > self.len() {
+        if self.storage.__try_make_mut().is_err() || self.capacity().wrapping_sub(self.len()) < N || index > self.len() {
             Err(value)
         } else {
             // Safety
@@ -1075,8 +1936,10 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// Removes the last element from a vector and returns it,
     /// Returns `None` if the collection is empty
     ///
-    /// Guaranteed to not panic/abort/allocate
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_pop(&mut self) -> Option<S::Item> {
+        self.storage.__try_make_mut().ok()?;
         if self.is_empty() {
             None
         } else {
@@ -1090,10 +1953,11 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// Removes the last `N` elements from a vector and returns it,
     /// Returns `None` if the collection is has less than N elements
     ///
-    /// Guaranteed to not panic/abort/allocate
-    #[cfg(any(doc, feature = "nightly"))]
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_pop_array<const N: usize>(&mut self) -> Option<[S::Item; N]> {
-        if self.is_empty() {
+        self.storage.__try_make_mut().ok()?;
+        if self.len() < N {
             None
         } else {
             // Safety
@@ -1107,8 +1971,10 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// shifting all elements after it to the left.
     /// Returns `None` if collection is empty or `index` is out of bounds.
     ///
-    /// Guaranteed to not panic/abort/allocate
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_remove(&mut self, index: usize) -> Option<S::Item> {
+        self.storage.__try_make_mut().ok()?;
         if self.len() < index {
             None
         } else {
@@ -1124,9 +1990,10 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// Returns `None` if the collection is has less than N elements
     /// or `index` is out of bounds.
     ///
-    /// Guaranteed to not panic/abort/allocate
-    #[cfg(any(doc, feature = "nightly"))]
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_remove_array<const N: usize>(&mut self, index: usize) -> Option<[S::Item; N]> {
+        self.storage.__try_make_mut().ok()?;
         if self.len() < index || self.len().wrapping_sub(index) < N {
             None
         } else {
@@ -1146,8 +2013,10 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     ///
     /// This does not preserve ordering, but is O(1).
     ///
-    /// Guaranteed to not panic/abort/allocate
+    /// Guaranteed to not panic/abort. This only allocates if the storage has to
+    /// copy a shared buffer before it can be modified
     pub fn try_swap_remove(&mut self, index: usize) -> Option<S::Item> {
+        self.storage.__try_make_mut().ok()?;
         if index < self.len() {
             // Safety
             //
@@ -1175,6 +2044,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         // the collection isn't full, so `ptr.add(len)` is valid to write
         unsafe {
             let len = self.len();
+            self.storage.__acquire(len..len.wrapping_add(1));
             self.set_len_unchecked(len.wrapping_add(1));
             let ptr = self.as_mut_ptr().add(len);
             ptr.write(value);
@@ -1187,7 +2057,10 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Safety
     ///
     /// the collection's remaining capacity must be at least N
-    #[cfg(any(doc, feature = "nightly"))]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is larger than the maximum capacity of a fixed capacity vector
     pub unsafe fn push_array_unchecked<const N: usize>(&mut self, value: [S::Item; N]) -> &mut [S::Item; N] {
         match S::CONST_CAPACITY {
             Some(n) if n < N => {
@@ -1202,9 +2075,10 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         // so `ptr.add(len)` is valid to write `N` elements
         unsafe {
             let len = self.len();
+            self.storage.__acquire(len..len.wrapping_add(N));
             self.set_len_unchecked(len.wrapping_add(N));
             let ptr = self.as_mut_ptr();
-            let out = ptr.add(len) as *mut [S::Item; N];
+            let out = ptr.add(len).cast::<[S::Item; N]>();
             out.write(value);
             &mut *out
         }
@@ -1230,6 +2104,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             // * the index is in bounds
             // * the collection is't full so `ptr.add(len)` is valid to write 1 element
             let len = self.len();
+            self.storage.__acquire(len..len.wrapping_add(1));
             self.set_len_unchecked(len.wrapping_add(1));
             let ptr = self.as_mut().as_mut_ptr().add(index);
             ptr.add(1).copy_from(ptr, len.wrapping_sub(index));
@@ -1244,8 +2119,11 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Safety
     ///
     /// * the collection's remaining capacity must be at least N
-    /// * hte index must be in bounds
-    #[cfg(any(doc, feature = "nightly"))]
+    /// * the index must be in bounds
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is larger than the maximum capacity of a fixed capacity vector
     pub unsafe fn insert_array_unchecked<const N: usize>(
         &mut self,
         index: usize,
@@ -1265,13 +2143,14 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         //   so `ptr.add(len)` is valid to write `N` elements
         unsafe {
             let len = self.len();
+            self.storage.__acquire(len..len.wrapping_add(N));
             self.set_len_unchecked(len.wrapping_add(N));
             let ptr = self.as_mut_ptr();
             let dist = len.wrapping_sub(index);
 
             let out = ptr.add(index);
             out.add(N).copy_from(out, dist);
-            let out = out as *mut [S::Item; N];
+            let out = out.cast::<[S::Item; N]>();
             out.write(value);
             &mut *out
         }
@@ -1295,7 +2174,9 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         unsafe {
             let len = len.wrapping_sub(1);
             self.set_len_unchecked(len);
-            self.as_mut_ptr().add(len).read()
+            let value = self.as_mut_ptr().add(len).read();
+            self.storage.__release(len..len + 1);
+            value
         }
     }
 
@@ -1304,19 +2185,20 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Safety
     ///
     /// The collection must contain at least `N` elements in it
-    #[cfg(any(doc, feature = "nightly"))]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is larger than the maximum capacity of a fixed capacity vector
     pub unsafe fn pop_array_unchecked<const N: usize>(&mut self) -> [S::Item; N] {
         match S::CONST_CAPACITY {
-            Some(n) if n < N => panic!("Tried to remove {} elements from a {} capacity vector!", N, n),
+            Some(n) if n < N => panic!("Tried to remove {N} elements from a {n} capacity vector!"),
             _ => (),
         }
 
         let len = self.len();
         debug_assert!(
-            len > N,
-            "Tried to remove {} elements from a {} length vector! This is UB in release mode",
-            N,
-            len,
+            len >= N,
+            "Tried to remove {N} elements from a {len} length vector! This is UB in release mode",
         );
         // Safety
         //
@@ -1324,7 +2206,9 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         unsafe {
             let len = len.wrapping_sub(N);
             self.set_len_unchecked(len);
-            self.as_mut_ptr().add(len).cast::<[S::Item; N]>().read()
+            let value = self.as_mut_ptr().add(len).cast::<[S::Item; N]>().read();
+            self.storage.__release(len..len + N);
+            value
         }
     }
 
@@ -1354,6 +2238,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             let ptr = self.as_mut().as_mut_ptr().add(index);
             let value = ptr.read();
             ptr.copy_from(ptr.add(1), len.wrapping_sub(index).wrapping_sub(1));
+            self.storage.__release(len.wrapping_sub(1)..len);
             value
         }
     }
@@ -1365,25 +2250,24 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     ///
     /// the collection must contain at least N elements, and
     /// index must be in bounds
-    #[cfg(any(doc, feature = "nightly"))]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is larger than the maximum capacity of a fixed capacity vector
     pub unsafe fn remove_array_unchecked<const N: usize>(&mut self, index: usize) -> [S::Item; N] {
         match S::CONST_CAPACITY {
-            Some(n) if n < N => panic!("Tried to remove {} elements from a {} capacity vector!", N, n),
+            Some(n) if n < N => panic!("Tried to remove {N} elements from a {n} capacity vector!"),
             _ => (),
         }
 
         let len = self.len();
         debug_assert!(
             index <= len,
-            "Tried to remove elements at index {} from a {} length vector! This is UB in release mode",
-            index,
-            len,
+            "Tried to remove elements at index {index} from a {len} length vector! This is UB in release mode",
         );
         debug_assert!(
-            len.wrapping_sub(index) > N,
-            "Tried to remove {} elements from a {} length vector! This is UB in release mode",
-            N,
-            len,
+            len.wrapping_sub(index) >= N,
+            "Tried to remove {N} elements from a {len} length vector! This is UB in release mode",
         );
 
         // Safety
@@ -1397,6 +2281,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             if N != 0 {
                 ptr.copy_from(ptr.add(N), len.wrapping_sub(index).wrapping_sub(N));
             }
+            self.storage.__release(len.wrapping_sub(N)..len);
             value
         }
     }
@@ -1423,6 +2308,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             let end = ptr.add(len.wrapping_sub(1));
             let value = at.read();
             at.copy_from(end, 1);
+            self.storage.__release(len.wrapping_sub(1)..len);
             value
         }
     }
@@ -1448,6 +2334,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     ///
     /// # Panics
     /// If the index is out of bounds
+    #[cfg(not(feature = "no-panic"))]
     pub fn split_off<B>(&mut self, index: usize) -> GenericVec<S::Item, B>
     where
         B: raw::StorageWithCapacity<Item = S::Item>,
@@ -1490,6 +2377,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     ///
     /// # Panics
     /// If the index is out of bounds
+    #[cfg(not(feature = "no-panic"))]
     pub fn split_off_into<B>(&mut self, index: usize, other: &mut GenericVec<S::Item, B>)
     where
         B: raw::Storage<Item = S::Item> + ?Sized,
@@ -1501,19 +2389,80 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
             self.len()
         );
 
+        other.reserve(self.len() - index);
+
+        // Safety
+        //
+        // * the index is in bounds
+        // * other has reserved enough space
+        unsafe { self.split_off_into_reserved(index, other) }
+    }
+
+    /// Moves the elements from the range `[at, len)` to the end of `other`, without reserving
+    ///
+    /// # Safety
+    ///
+    /// * `index` must be in bounds
+    /// * `other` must have enough remaining capacity for `len - index` elements
+    unsafe fn split_off_into_reserved<B>(&mut self, index: usize, other: &mut GenericVec<S::Item, B>)
+    where
+        B: raw::Storage<Item = S::Item> + ?Sized,
+    {
         unsafe {
             // Safety
             //
             // * the index is in bounds
             // * other has reserved enough space
             // * we ignore all elements after index
-            let slice = self.get_unchecked(index..);
-            other.reserve(slice.len());
+            let len = self.len();
+            let slice = self.get_unchecked_mut(index..);
             other.extend_from_slice_unchecked(slice);
             self.set_len_unchecked(index);
+            self.storage.__release(index..len);
         }
     }
 
+    /// Tries to split the collection into two at the given index.
+    ///
+    /// This is the fallible version of [`GenericVec::split_off`]
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 4>::from_array([1, 2, 3, 4]);
+    /// assert!(vec.try_split_off::<[_; 2]>(1).is_err());
+    /// assert_eq!(vec.try_split_off::<[_; 2]>(2).unwrap(), [3, 4]);
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if it's not possible to create a storage that can hold the
+    /// elements in the range `[at, len)`. Then the vector is left unchanged.
+    ///
+    /// # Panics
+    /// If the index is out of bounds
+    pub fn try_split_off<B>(&mut self, index: usize) -> Result<GenericVec<S::Item, B>, AllocError>
+    where
+        B: raw::StorageWithCapacity<Item = S::Item>,
+    {
+        assert!(
+            index <= self.len(),
+            "Tried to split at index {}, but length is {}",
+            index,
+            self.len()
+        );
+
+        let mut vec = GenericVec::<S::Item, B>::try_with_capacity(self.len() - index)?;
+
+        // Safety
+        //
+        // * the index is in bounds
+        // * the new vector has enough capacity
+        unsafe { self.split_off_into_reserved(index, &mut vec) }
+
+        Ok(vec)
+    }
+
     /// Moves all the elements of `other` into `Self`, leaving `other` empty.
     ///
     /// Does not change the capacity of either collection.
@@ -1534,11 +2483,13 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Panic
     ///
     /// May panic or reallocate if the collection is full
+    #[cfg(not(feature = "no-panic"))]
     pub fn append<B: Storage<Item = S::Item> + ?Sized>(&mut self, other: &mut GenericVec<S::Item, B>) {
         other.split_off_into(0, self);
     }
 
     /// Convert the backing storage type, and moves all the elements in `self` to the new vector
+    #[cfg(not(feature = "no-panic"))]
     pub fn convert<B: raw::StorageWithCapacity<Item = S::Item>>(mut self) -> GenericVec<S::Item, B>
     where
         S: Sized,
@@ -1546,10 +2497,24 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         self.split_off(0)
     }
 
-    /// Creates a raw cursor that can be used to remove elements in the specified range.
-    /// Usage of [`RawCursor`](iter::RawCursor) is `unsafe` because it doesn't do any checks.
-    /// [`RawCursor`](iter::RawCursor) is meant to be a low level tool to implement fancier
-    /// iterators, like [`GenericVec::drain`], [`GenericVec::drain_filter`],
+    /// Tries to convert the backing storage type, and moves all the elements in `self` to the new vector
+    ///
+    /// # Errors
+    /// Returns `Err(self)` if it's not possible to create a storage that can hold all the elements
+    pub fn try_convert<B: raw::StorageWithCapacity<Item = S::Item>>(mut self) -> Result<GenericVec<S::Item, B>, Self>
+    where
+        S: Sized,
+    {
+        match self.try_split_off(0) {
+            Ok(vec) => Ok(vec),
+            Err(AllocError) => Err(self),
+        }
+    }
+
+    /// Creates a raw cursor that can be used to remove elements in the specified range.
+    /// Usage of [`RawCursor`](iter::RawCursor) is `unsafe` because it doesn't do any checks.
+    /// [`RawCursor`](iter::RawCursor) is meant to be a low level tool to implement fancier
+    /// iterators, like [`GenericVec::drain`], [`GenericVec::drain_filter`],
     /// or [`GenericVec::splice`].
     ///
     /// # Panic
@@ -1618,6 +2583,74 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         iter::DrainFilter::new(self.raw_cursor(range), f)
     }
 
+    /// Creates an iterator which uses a closure to determine if an element should be removed,
+    /// or if the iteration should stop.
+    ///
+    /// If the closure returns `ControlFlow::Continue(true)`, then the element is removed and yielded.
+    /// If the closure returns `ControlFlow::Continue(false)`, the element will remain in the vector
+    /// and will not be yielded by the iterator.
+    /// If the closure returns `ControlFlow::Break(value)`, then `Err(value)` is yielded, and the
+    /// iterator stops. That element, and all elements that weren't visited yet, remain in the vector.
+    ///
+    /// ```rust
+    /// use core::ops::ControlFlow;
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([1, 2, 3, -4, 5, 6]).unwrap();
+    /// let mut filter = vec.try_drain_filter(.., |&mut x| {
+    ///     if x < 0 {
+    ///         ControlFlow::Break(x)
+    ///     } else {
+    ///         ControlFlow::Continue(x % 2 == 0)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(filter.next(), Some(Ok(2)));
+    /// assert_eq!(filter.next(), Some(Err(-4)));
+    /// assert_eq!(filter.next(), None);
+    /// drop(filter);
+    /// assert_eq!(vec, [1, 3, -4, 5, 6]);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point
+    /// is greater than the length of the vector.
+    #[inline]
+    pub fn try_drain_filter<R, F, B>(&mut self, range: R, f: F) -> iter::TryDrainFilter<'_, S, F, B>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut S::Item) -> ControlFlow<B, bool>,
+    {
+        iter::TryDrainFilter::new(self.raw_cursor(range), f)
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// This is the same as [`GenericVec::drain_filter`], but the closure is also passed
+    /// the index that the element had in the vector, before any elements were removed.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<char, 8>::try_from_partial_array(['a', 'b', 'c', 'd', 'e']).unwrap();
+    /// assert!(vec.drain_filter_indexed(1.., |i, _| i % 2 == 0).eq(['c', 'e']));
+    /// assert_eq!(vec, ['a', 'b', 'd']);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point
+    /// is greater than the length of the vector.
+    #[inline]
+    pub fn drain_filter_indexed<R, F>(&mut self, range: R, f: F) -> iter::DrainFilterIndexed<'_, S, F>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(usize, &mut S::Item) -> bool,
+    {
+        iter::DrainFilterIndexed::new(self.raw_cursor(range), f)
+    }
+
     /// Creates a splicing iterator that replaces the specified range in the vector with
     /// the given `replace_with` iterator and yields the removed items. `replace_with` does
     /// not need to be the same length as range.
@@ -1635,6 +2668,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// Panics if the starting point is greater than the end point or if the end point
     /// is greater than the length of the vector.
     #[inline]
+    #[cfg(not(feature = "no-panic"))]
     pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> iter::Splice<'_, S, I::IntoIter>
     where
         R: RangeBounds<usize>,
@@ -1643,6 +2677,51 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         iter::Splice::new(self.raw_cursor(range), replace_with.into_iter())
     }
 
+    /// Replaces the specified range in the vector with clones of the elements in `slice`,
+    /// and drops the removed elements.
+    ///
+    /// This moves the elements after the range at most once, and never allocates
+    /// unless the vector has to grow to hold the new elements.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::from_partial_array([1, 2, 3, 4]);
+    /// vec.splice_from_slice(1..3, &[7, 8, 9]);
+    /// assert_eq!(vec, [1, 7, 8, 9, 4]);
+    /// vec.splice_from_slice(..4, &[0]);
+    /// assert_eq!(vec, [0, 4]);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// * Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the vector.
+    /// * May panic or reallocate if the collection doesn't have enough remaining capacity
+    #[doc(alias = "replace_range")]
+    #[cfg(not(feature = "no-panic"))]
+    pub fn splice_from_slice<R>(&mut self, range: R, slice: &[S::Item])
+    where
+        R: RangeBounds<usize>,
+        S::Item: Clone,
+    {
+        let mut raw = self.raw_cursor(range);
+
+        // Safety
+        //
+        // * the removed elements are dropped before any new elements are written
+        // * after `reserve` there are at least `slice.len()` unfilled slots at the front
+        // * if `clone` panics, the rest of the gap is closed when the cursor is dropped
+        unsafe {
+            raw.drop_n_front(raw.len());
+            raw.reserve(slice.len());
+
+            for value in slice {
+                raw.write_front(value.clone());
+            }
+        }
+    }
+
     /// Retains only the elements specified by the predicate.
     ///
     /// In other words, remove all elements `e` such that `f(e)` returns false.
@@ -1659,6 +2738,225 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         self.drain_filter(.., not(f));
     }
 
+    /// Retains only the elements specified by the predicate, passing each element
+    /// along with its index in the vector, before any elements were removed.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([1, 1, 1, 1, 1]).unwrap();
+    /// vec.retain_indexed(|i, _| i != 2);
+    /// assert_eq!(vec, [1, 1, 1, 1]);
+    /// ```
+    #[inline]
+    pub fn retain_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut S::Item) -> bool,
+    {
+        self.drain_filter_indexed(.., |index, value| !f(index, value));
+    }
+
+    /// Retains only the elements specified by the fallible predicate.
+    ///
+    /// The elements are visited in the original order. On the first `Err`, no
+    /// more elements are visited, and the error is returned. The vector is left
+    /// with every element that was retained, the element that caused the error,
+    /// and the elements that weren't visited yet, in their original order.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([1, 2, 3, -4, 5, 6]).unwrap();
+    /// let result = vec.try_retain(|&mut x| if x < 0 { Err(x) } else { Ok(x % 2 == 1) });
+    /// assert_eq!(result, Err(-4));
+    /// assert_eq!(vec, [1, 3, -4, 5, 6]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`
+    #[inline]
+    pub fn try_retain<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut S::Item) -> Result<bool, E>,
+    {
+        let mut filter = self.try_drain_filter(.., |value| match f(value) {
+            Ok(keep) => ControlFlow::Continue(!keep),
+            Err(err) => ControlFlow::Break(err),
+        });
+
+        match filter.find_map(Result::err) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Retains only the elements specified by the predicate, and moves the
+    /// other elements to the end of `other`, in their original order.
+    ///
+    /// Consecutive elements that are moved to `other` are moved together.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::{ArrayVec, HeapVec};
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::from_partial_array([1, 2, 4, 5, 6, 7]);
+    /// let mut evens = HeapVec::new();
+    /// vec.retain_into(&mut evens, |&mut x| x % 2 == 1);
+    /// assert_eq!(vec, [1, 5, 7]);
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if `other` is full
+    ///
+    /// # Panic behavor
+    ///
+    /// If `f` panics, or `other` is full, then the elements that were not yet
+    /// moved to `other` remain in the vector.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn retain_into<B, F>(&mut self, other: &mut GenericVec<S::Item, B>, f: F)
+    where
+        B: ?Sized + Storage<Item = S::Item>,
+        F: FnMut(&mut S::Item) -> bool,
+    {
+        let _ = self.retain_into_with(other, f, |other, additional| {
+            other.reserve(additional);
+            Ok(())
+        });
+    }
+
+    /// Retains only the elements specified by the predicate, and moves the
+    /// other elements to the end of `other`, in their original order,
+    /// until `other` is full.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([1, 2, 4, 5, 6, 7]).unwrap();
+    /// let mut evens = ArrayVec::<i32, 2>::new();
+    /// assert!(vec.try_retain_into(&mut evens, |&mut x| x % 2 == 1).is_err());
+    /// assert_eq!(vec, [1, 5, 6, 7]);
+    /// assert_eq!(evens, [2, 4]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(_)` if `other` is full, and it's not possible to reserve more space.
+    /// Then the elements that were not moved to `other` remain in the vector, in their
+    /// original order, even if they were rejected by `f`.
+    pub fn try_retain_into<B, F>(&mut self, other: &mut GenericVec<S::Item, B>, f: F) -> AllocResult
+    where
+        B: ?Sized + Storage<Item = S::Item>,
+        F: FnMut(&mut S::Item) -> bool,
+    {
+        self.retain_into_with(other, f, GenericVec::try_reserve)
+    }
+
+    /// Splits the vector into a vector of the elements for which `f` returns `true`,
+    /// and a vector of the elements for which `f` returns `false`.
+    ///
+    /// Both vectors keep the original order of the elements.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<i32, 8>::from_partial_array([1, 2, 4, 5, 6, 7]);
+    /// let (odds, evens) = vec.partition_into::<[_; 4], Box<[_]>, _>(|&mut x| x % 2 == 1);
+    /// assert_eq!(odds, [1, 5, 7]);
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic if either of the new vectors can't hold their elements
+    #[cfg(not(feature = "no-panic"))]
+    pub fn partition_into<A, B, F>(mut self, f: F) -> (GenericVec<S::Item, A>, GenericVec<S::Item, B>)
+    where
+        S: Sized,
+        A: raw::StorageWithCapacity<Item = S::Item>,
+        B: raw::StorageWithCapacity<Item = S::Item>,
+        F: FnMut(&mut S::Item) -> bool,
+    {
+        let mut rejected = GenericVec::with_capacity(0);
+        self.retain_into(&mut rejected, f);
+        (self.convert(), rejected)
+    }
+
+    /// Retains only the elements specified by the predicate, and moves each run of
+    /// consecutive rejected elements to `other` after `reserve`-ing space for them
+    ///
+    /// If `reserve` fails, as many elements as fit are moved, and then the error is returned
+    fn retain_into_with<B, F, R>(&mut self, other: &mut GenericVec<S::Item, B>, mut f: F, mut reserve: R) -> AllocResult
+    where
+        B: ?Sized + Storage<Item = S::Item>,
+        F: FnMut(&mut S::Item) -> bool,
+        R: FnMut(&mut GenericVec<S::Item, B>, usize) -> AllocResult,
+    {
+        let original_len = self.len();
+        let ptr = self.as_mut_ptr();
+
+        // Safety: the guard restores the length of the vector
+        unsafe { self.set_len_unchecked(0) }
+
+        let mut guard = RetainGuard {
+            vec: self,
+            original_len,
+            write: 0,
+            read: 0,
+        };
+
+        let mut next_keep = None;
+
+        while guard.read < original_len {
+            // Safety
+            //
+            // * all the elements in `read..original_len` are still in place
+            // * `f` is called exactly once for each element
+            unsafe {
+                let keep = match next_keep.take() {
+                    Some(keep) => keep,
+                    None => f(&mut *ptr.add(guard.read)),
+                };
+
+                let mut end = guard.read + 1;
+
+                while end < original_len {
+                    let next = f(&mut *ptr.add(end));
+
+                    if next != keep {
+                        next_keep = Some(next);
+                        break
+                    }
+
+                    end += 1;
+                }
+
+                let run = end - guard.read;
+
+                if keep {
+                    if guard.write != guard.read {
+                        ptr.add(guard.write).copy_from(ptr.add(guard.read), run);
+                    }
+                    guard.write += run;
+                    guard.read = end;
+                } else {
+                    let result = reserve(other, run);
+                    let moved = match result {
+                        Ok(()) => run,
+                        Err(_) => run.min(other.remaining_capacity()),
+                    };
+
+                    other.extend_from_slice_unchecked(core::slice::from_raw_parts(ptr.add(guard.read), moved));
+                    guard.read += moved;
+                    result?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Shallow copies and appends all elements in a slice to the `GenericVec`.
     ///
     /// # Safety
@@ -1673,6 +2971,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
 
         unsafe {
             let len = self.len();
+            self.storage.__acquire(len..len.wrapping_add(slice.len()));
             self.as_mut_ptr()
                 .add(len)
                 .copy_from_nonoverlapping(slice.as_ptr(), slice.len());
@@ -1694,6 +2993,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// If `T::clone` panics, then all newly added items will be dropped. This is different
     /// from `std`, where on panic, newly added items will stay in the `Vec`. This behavior
     /// is unstable, and may change in the future.
+    #[cfg(not(feature = "no-panic"))]
     pub fn extend_from_slice(&mut self, slice: &[S::Item])
     where
         S::Item: Clone,
@@ -1703,7 +3003,168 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         // Safety
         //
         // We reserved enough space
-        unsafe { extension::Extension::extend_from_slice(self, slice) }
+        unsafe { self.extend_from_slice_reserved(slice) }
+    }
+
+    /// Tries to clone and append all elements in a slice to the `GenericVec`.
+    ///
+    /// This is the fallible version of [`GenericVec::extend_from_slice`]
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if there isn't enough space for the slice, and it's not possible
+    /// to reserve more space. Then the vector is left unchanged.
+    pub fn try_extend_from_slice(&mut self, slice: &[S::Item]) -> AllocResult
+    where
+        S::Item: Clone,
+    {
+        self.try_reserve(slice.len())?;
+
+        // Safety
+        //
+        // We reserved enough space
+        unsafe { self.extend_from_slice_reserved(slice) }
+        Ok(())
+    }
+
+    /// Tries to append all the items of the iterator to the `GenericVec`.
+    ///
+    /// This is the fallible version of [`Extend::extend`]. Items are appended until
+    /// the vector is full and it's not possible to reserve more space.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 4>::new();
+    /// let rest = vec.try_extend(0..6).unwrap_err();
+    /// assert_eq!(vec, [0, 1, 2, 3]);
+    /// assert!(rest.eq([4, 5]));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the rest of the iterator, starting with the item that didn't fit,
+    /// if it's not possible to reserve enough space. All the items before that
+    /// stay in the vector.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), core::iter::Chain<core::iter::Once<S::Item>, I::IntoIter>>
+    where
+        I: IntoIterator<Item = S::Item>,
+    {
+        let mut iter = iter.into_iter();
+        let _ = self.try_reserve(iter.size_hint().0);
+
+        for value in iter.by_ref() {
+            if self.is_full() && self.try_reserve(1).is_err() {
+                return Err(core::iter::once(value).chain(iter))
+            }
+
+            // Safety: there is space for at least 1 more element
+            unsafe { self.push_unchecked(value) };
+        }
+
+        Ok(())
+    }
+
+    /// Clones the elements in the range `src` and appends them to the end of the vector
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::from_partial_array([0, 1, 2, 3]);
+    /// vec.extend_from_within(1..3);
+    /// assert_eq!(vec, [0, 1, 2, 3, 1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// * May panic or reallocate if the collection doesn't have enough remaining capacity
+    /// * Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the vector.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn extend_from_within<R>(&mut self, src: R)
+    where
+        R: RangeBounds<usize>,
+        S::Item: Clone,
+    {
+        let range = slice::check_range(self.len(), src);
+        self.reserve(range.len());
+
+        // Safety
+        //
+        // * the range is in bounds
+        // * we reserved enough space
+        unsafe { self.extend_from_within_reserved(range) }
+    }
+
+    /// Tries to clone the elements in the range `src` and append them to the end of the vector
+    ///
+    /// This is the fallible version of [`GenericVec::extend_from_within`]
+    ///
+    /// # Errors
+    /// Returns `Err(_)` if there isn't enough space for the elements, and it's not possible
+    /// to reserve more space. Then the vector is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point
+    /// is greater than the length of the vector.
+    pub fn try_extend_from_within<R>(&mut self, src: R) -> AllocResult
+    where
+        R: RangeBounds<usize>,
+        S::Item: Clone,
+    {
+        let range = slice::check_range(self.len(), src);
+        self.try_reserve(range.len())?;
+
+        // Safety
+        //
+        // * the range is in bounds
+        // * we reserved enough space
+        unsafe { self.extend_from_within_reserved(range) }
+        Ok(())
+    }
+
+    /// Clones the elements in the range and appends them to the end of the vector, without reserving
+    ///
+    /// # Safety
+    ///
+    /// * the range must be in bounds
+    /// * There must be at least `range.len()` remaining capacity in the vector
+    unsafe fn extend_from_within_reserved(&mut self, range: core::ops::Range<usize>)
+    where
+        S::Item: Clone,
+    {
+        let additional = range.len();
+
+        // Safety
+        //
+        // * the range is inside the first `len` items, which are initialized
+        // * there is enough spare capacity for every item in the range,
+        //   and it doesn't overlap the range
+        // * if a clone panics, the writer drops the clones, and then the slots are released
+        self.fill_spare(additional, |vec| unsafe {
+            let len = vec.len();
+            let ptr = vec.storage.as_mut().as_mut_ptr();
+            let items = core::slice::from_raw_parts(ptr.add(range.start).cast::<S::Item>(), additional);
+            let mut writer = SliceVec::new(core::slice::from_raw_parts_mut(ptr.add(len), additional));
+
+            for value in items {
+                writer.push_unchecked(value.clone());
+            }
+
+            let written = ManuallyDrop::new(writer).len();
+            vec.set_len_unchecked(len + written);
+        });
+    }
+
+    /// Clones and appends all elements in a slice to the `GenericVec`, without reserving
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `slice.len()` remaining capacity in the vector
+    pub(crate) unsafe fn extend_from_slice_reserved(&mut self, slice: &[S::Item])
+    where
+        S::Item: Clone,
+    {
+        self.fill_spare(slice.len(), |vec| unsafe { extension::Extension::extend_from_slice(vec, slice) });
     }
 
     /// Replaces all of the current elements with the ones in the slice
@@ -1721,6 +3182,7 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
     /// # Panic
     ///
     /// May try to panic/reallocate if there is not enough capacity for the slice
+    #[cfg(not(feature = "no-panic"))]
     pub fn clone_from(&mut self, source: &[S::Item])
     where
         S::Item: Clone,
@@ -1785,22 +3247,271 @@ impl<S: ?Sized + Storage> SimpleVec<S> {
         self.dedup_by(key_to_same_bucket(key));
     }
 
-    /// Removes all but the first of consecutive elements in the vector that resolve to the same key.
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation.
     ///
     /// If the vector is sorted, this removes all duplicates.
-    pub fn dedup<F, K>(&mut self)
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([1, 2, 2, 3, 2]).unwrap();
+    /// vec.dedup();
+    /// assert_eq!(vec, [1, 2, 3, 2]);
+    /// ```
+    pub fn dedup(&mut self)
     where
         S::Item: PartialEq,
     {
-        #[inline]
-        fn eq_to_same_buckets<T, F>(mut f: F) -> impl FnMut(&mut T, &mut T) -> bool
-        where
-            F: FnMut(&T, &T) -> bool,
-        {
-            #[inline]
-            move |a, b| f(a, b)
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive elements in the vector satisfying
+    /// a given equality relation, and appends the removed elements to `removed`
+    /// in the order they were in the vector.
+    ///
+    /// See [`GenericVec::dedup_by`] for how `same_bucket` is called.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::{ArrayVec, HeapVec};
+    ///
+    /// let mut vec = ArrayVec::<&str, 8>::from_partial_array(["foo", "bar", "Bar", "baz", "bar"]);
+    /// let mut removed = HeapVec::new();
+    /// vec.dedup_by_into(&mut removed, |a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(vec, ["foo", "bar", "baz", "bar"]);
+    /// assert_eq!(removed, ["Bar"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if `removed` is full
+    ///
+    /// # Panic behavor
+    ///
+    /// If `same_bucket` panics, or `removed` is full, then the vector is left
+    /// with the elements that were not yet checked, and the removed element that
+    /// didn't fit in `removed` is dropped
+    #[cfg(not(feature = "no-panic"))]
+    pub fn dedup_by_into<B, F>(&mut self, removed: &mut GenericVec<S::Item, B>, same_bucket: F)
+    where
+        B: ?Sized + Storage<Item = S::Item>,
+        F: FnMut(&mut S::Item, &mut S::Item) -> bool,
+    {
+        self.dedup_by_with(same_bucket, |_, item| {
+            removed.push(item);
+        });
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that resolve
+    /// to the same key, and appends the removed elements to `removed`
+    /// in the order they were in the vector.
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if `removed` is full
+    #[cfg(not(feature = "no-panic"))]
+    pub fn dedup_by_key_into<B, F, K>(&mut self, removed: &mut GenericVec<S::Item, B>, mut key: F)
+    where
+        B: ?Sized + Storage<Item = S::Item>,
+        F: FnMut(&mut S::Item) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by_into(removed, |a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation, and appends the removed elements
+    /// to `removed` in the order they were in the vector.
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if `removed` is full
+    #[cfg(not(feature = "no-panic"))]
+    pub fn dedup_into<B>(&mut self, removed: &mut GenericVec<S::Item, B>)
+    where
+        B: ?Sized + Storage<Item = S::Item>,
+        S::Item: PartialEq,
+    {
+        self.dedup_by_into(removed, |a, b| a == b);
+    }
+
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation, and appends the length of each run
+    /// of equal elements to `counts`.
+    ///
+    /// After the call, the element at index `i` of the vector was repeated
+    /// `counts[i]` times, if `counts` started out empty.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<char, 8>::from_partial_array(['a', 'a', 'a', 'b', 'c', 'c']);
+    /// let mut counts = ArrayVec::<usize, 8>::new();
+    /// vec.dedup_with_count(&mut counts);
+    /// assert_eq!(vec, ['a', 'b', 'c']);
+    /// assert_eq!(counts, [3, 1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if `counts` is full
+    #[cfg(not(feature = "no-panic"))]
+    pub fn dedup_with_count<B>(&mut self, counts: &mut GenericVec<usize, B>)
+    where
+        B: ?Sized + Storage<Item = usize>,
+        S::Item: PartialEq,
+    {
+        self.dedup_by_with_count(counts, |a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive elements in the vector satisfying
+    /// a given equality relation, and appends the length of each run of equal
+    /// elements to `counts`.
+    ///
+    /// See [`GenericVec::dedup_by`] for how `same_bucket` is called.
+    ///
+    /// # Panics
+    ///
+    /// May panic or reallocate if `counts` is full
+    #[cfg(not(feature = "no-panic"))]
+    pub fn dedup_by_with_count<B, F>(&mut self, counts: &mut GenericVec<usize, B>, same_bucket: F)
+    where
+        B: ?Sized + Storage<Item = usize>,
+        F: FnMut(&mut S::Item, &mut S::Item) -> bool,
+    {
+        let start = counts.len();
+
+        self.dedup_by_with(same_bucket, |kept, _| {
+            // every element before `kept` was already counted
+            while counts.len() - start <= kept {
+                counts.push(1);
+            }
+            counts[start + kept] += 1;
+        });
+
+        while counts.len() - start < self.len() {
+            counts.push(1);
+        }
+    }
+
+    /// Removes all but the first of consecutive elements in the vector satisfying
+    /// a given equality relation, and passes each removed element to `removed`
+    /// along with the index of the element it is a duplicate of.
+    #[cfg(not(feature = "no-panic"))]
+    fn dedup_by_with<F, R>(&mut self, mut same_bucket: F, mut removed: R)
+    where
+        F: FnMut(&mut S::Item, &mut S::Item) -> bool,
+        R: FnMut(usize, S::Item),
+    {
+        let mut cursor = self.raw_cursor(..);
+
+        if cursor.is_empty() {
+            return
+        }
+
+        // Safety: the cursor is not empty
+        unsafe { cursor.skip_front() }
+        let mut kept = 0;
+
+        while !cursor.is_empty() {
+            // Safety
+            //
+            // * the cursor is not empty
+            // * the first element was kept, so there is always an element in front of the cursor
+            // * the last kept element and the front of the cursor don't overlap
+            let is_duplicate = unsafe {
+                let last_kept = cursor.last_kept_front();
+                same_bucket(cursor.front_mut(), &mut *last_kept)
+            };
+
+            if is_duplicate {
+                // Safety: the cursor is not empty
+                removed(kept, unsafe { cursor.take_front() });
+            } else {
+                // Safety: the cursor is not empty
+                unsafe { cursor.skip_front() }
+                kept += 1;
+            }
+        }
+    }
+
+    /// Removes all but the first occurrence of each element in the vector, according
+    /// to the [`Hash`](core::hash::Hash) and [`Eq`] trait implementations.
+    ///
+    /// Unlike [`GenericVec::dedup`], the vector doesn't need to be sorted. The remaining
+    /// elements keep the order of their first occurrence.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([3, 1, 3, 2, 1, 4]).unwrap();
+    /// vec.dedup_unsorted();
+    /// assert_eq!(vec, [3, 1, 2, 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn dedup_unsorted(&mut self)
+    where
+        S::Item: core::hash::Hash + Eq,
+    {
+        use core::hash::{Hash, Hasher};
+
+        /// A kept element, which doesn't move until the cursor is finished
+        struct Kept<T>(*const T);
+
+        impl<T: Hash> Hash for Kept<T> {
+            // Safety: `Kept` always points to a kept element of the vector
+            fn hash<H: Hasher>(&self, state: &mut H) { unsafe { (*self.0).hash(state) } }
+        }
+
+        impl<T: PartialEq> PartialEq for Kept<T> {
+            // Safety: `Kept` always points to a kept element of the vector
+            fn eq(&self, other: &Self) -> bool { unsafe { *self.0 == *other.0 } }
         }
 
-        self.dedup_by(eq_to_same_buckets(PartialEq::eq));
+        impl<T: Eq> Eq for Kept<T> {}
+
+        let mut cursor = self.raw_cursor(..);
+        let mut seen = std::collections::HashSet::new();
+
+        while !cursor.is_empty() {
+            // Safety: the cursor is not empty
+            let front = core::ptr::from_ref(unsafe { cursor.front() });
+
+            if seen.contains(&Kept(front)) {
+                // Safety: the cursor is not empty
+                unsafe { cursor.drop_front() }
+            } else {
+                // Safety
+                //
+                // * the cursor is not empty
+                // * the element was just kept, so it is in front of the cursor
+                unsafe {
+                    cursor.skip_front();
+                    seen.insert(Kept(cursor.last_kept_front()));
+                }
+            }
+        }
+    }
+
+    /// Removes all but the first element in the vector that resolves to each key.
+    ///
+    /// Unlike [`GenericVec::dedup_by_key`], the vector doesn't need to be sorted. The remaining
+    /// elements keep the order of their first occurrence.
+    ///
+    /// ```rust
+    /// use cl_generic_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<i32, 8>::try_from_partial_array([10, 21, 12, 30, 23]).unwrap();
+    /// vec.dedup_by_key_unsorted(|x| *x % 10);
+    /// assert_eq!(vec, [10, 21, 12, 23]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn dedup_by_key_unsorted<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut S::Item) -> K,
+        K: core::hash::Hash + Eq,
+    {
+        let mut seen = std::collections::HashSet::new();
+        self.retain(|item| seen.insert(key(item)));
     }
 }
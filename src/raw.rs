@@ -1,17 +1,39 @@
 //! The raw vector type that back-up the [`GenericVec`](crate::GenericVec)
 
-use core::mem::MaybeUninit;
+use core::{mem::MaybeUninit, ops::Range};
 #[cfg(feature = "alloc")]
 use std::boxed::Box;
 
 mod array;
+mod checked;
 #[cfg(any(doc, feature = "alloc"))]
 pub(crate) mod heap;
+#[cfg(all(feature = "guard-pages", target_os = "linux"))]
+mod guarded;
+mod instrumented;
+#[cfg(any(doc, feature = "alloc"))]
+mod shared;
+mod poison;
 mod slice;
+mod zeroize;
 
 mod capacity;
 
+#[cfg(all(feature = "guard-pages", target_os = "linux"))]
+#[cfg_attr(doc, doc(cfg(all(feature = "guard-pages", target_os = "linux"))))]
+pub use guarded::{GuardAlign, GuardedHeap};
+#[cfg(any(doc, feature = "alloc"))]
+pub use shared::{ArcStorage, RcStorage};
+pub use array::FlatArray;
+pub use checked::Checked;
+#[cfg(feature = "std")]
+pub use instrumented::SharedStats;
+pub use instrumented::{Instrumented, Stats, StatsSink};
+pub use poison::Poison;
+pub use zeroize::Zeroizing;
+
 /// Error on failure to allocate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AllocError;
 /// Result of an allocation
 pub type AllocResult = Result<(), AllocError>;
@@ -49,6 +71,51 @@ pub unsafe trait Storage: AsRef<[MaybeUninit<Self::Item>]> + AsMut<[MaybeUninit<
     /// # Errors
     /// If enough space cannot be reserved, returns Err(AllocError)
     fn try_reserve(&mut self, new_capacity: usize) -> AllocResult;
+
+    /// The number of bytes of heap memory that this storage owns, not counting
+    /// the heap memory owned by the items in the storage
+    ///
+    /// Storages that keep their buffer inline (like arrays), or borrow it (like slices)
+    /// don't own any heap memory. This is used by [`HeapSize`](crate::HeapSize).
+    #[inline]
+    fn heap_bytes(&self) -> usize { 0 }
+
+    /// Drops the items in `range`, which the vector is giving up
+    ///
+    /// # Safety
+    ///
+    /// All the items in `range` must be initialized, and must not be used after this call
+    #[doc(hidden)]
+    #[inline]
+    unsafe fn __drop_items(&mut self, range: Range<usize>) {
+        unsafe { core::ptr::drop_in_place(crate::slice_assume_init_mut(&mut self.as_mut()[range])) }
+    }
+
+    /// Notifies the storage that the slots in `range` no longer hold any items
+    ///
+    /// This is called after the items were dropped or moved out of the slots
+    #[doc(hidden)]
+    #[inline]
+    fn __release(&mut self, _range: Range<usize>) {}
+
+    /// Notifies the storage that items are about to be written to the slots in `range`
+    #[doc(hidden)]
+    #[inline]
+    fn __acquire(&mut self, _range: Range<usize>) {}
+
+    /// Notifies the storage that the vector now holds `len` items
+    #[doc(hidden)]
+    #[inline]
+    fn __set_len(&mut self, _len: usize) {}
+
+    /// Makes sure that `as_mut` doesn't need to allocate, for storages that
+    /// have to copy their buffer before it can be modified
+    ///
+    /// # Errors
+    /// If the buffer can't be copied, returns `Err(AllocError)`
+    #[doc(hidden)]
+    #[inline]
+    fn __try_make_mut(&mut self) -> AllocResult { Ok(()) }
 }
 
 /// A storage that can be initially created with a given capacity
@@ -61,6 +128,16 @@ pub unsafe trait StorageWithCapacity: Storage + Sized {
     /// Creates a new storage with at least the given storage capacity
     fn with_capacity(capacity: usize) -> Self;
 
+    /// Tries to create a new storage with at least the given storage capacity
+    ///
+    /// # Errors
+    /// If a storage with enough space cannot be created, returns `Err(AllocError)`
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        let mut storage = Self::with_capacity(0);
+        storage.try_reserve(capacity)?;
+        Ok(storage)
+    }
+
     #[doc(hidden)]
     #[allow(non_snake_case)]
     fn __with_capacity__const_capacity_checked(capacity: usize, _old_capacity: Option<usize>) -> Self {
@@ -68,6 +145,35 @@ pub unsafe trait StorageWithCapacity: Storage + Sized {
     }
 }
 
+/// A storage of arrays that can be turned into a storage of the items of those arrays
+///
+/// This is used by [`GenericVec::into_flattened`](crate::GenericVec::into_flattened).
+/// It is implemented for heap, slice and array storages (array storages are flattened
+/// into a [`FlatArray`]), and for the [`Zeroizing`] and [`Poison`] wrappers.
+///
+/// # Safety
+///
+/// The flattened storage must contain the same slots as this storage, so the `i`th item
+/// of the `j`th array must be in the `j * N + i`th slot of the flattened storage, and the
+/// flattened storage must have a capacity of at least `N` times the capacity of this storage.
+pub unsafe trait FlattenStorage<T, const N: usize>: Storage<Item = [T; N]> + Sized {
+    /// The storage of the items of the arrays
+    type Flattened: Storage<Item = T>;
+
+    /// Turn this storage into a storage of the items of the arrays
+    fn flatten(self) -> Self::Flattened;
+}
+
+unsafe impl<'a, T, const N: usize> FlattenStorage<T, N> for &'a mut [MaybeUninit<[T; N]>] {
+    type Flattened = &'a mut [MaybeUninit<T>];
+
+    fn flatten(self) -> Self::Flattened {
+        let len = self.len().saturating_mul(N);
+        // Safety: `[MaybeUninit<[T; N]>]` has the same layout as `N` times as many `MaybeUninit<T>`
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), len) }
+    }
+}
+
 unsafe impl<S: ?Sized + Storage> Storage for &mut S {
     type Item = S::Item;
 
@@ -78,6 +184,26 @@ unsafe impl<S: ?Sized + Storage> Storage for &mut S {
     fn reserve(&mut self, new_capacity: usize) { S::reserve(self, new_capacity); }
     #[inline]
     fn try_reserve(&mut self, new_capacity: usize) -> AllocResult { S::try_reserve(self, new_capacity) }
+
+    #[doc(hidden)]
+    #[inline]
+    unsafe fn __drop_items(&mut self, range: Range<usize>) { unsafe { S::__drop_items(self, range) } }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __release(&mut self, range: Range<usize>) { S::__release(self, range) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __acquire(&mut self, range: Range<usize>) { S::__acquire(self, range) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __set_len(&mut self, len: usize) { S::__set_len(self, len) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __try_make_mut(&mut self) -> AllocResult { S::__try_make_mut(self) }
 }
 
 /// Wrapper for a [`Box<S>`]. Needed to implement some traits that could not be implemented on Box directly
@@ -105,6 +231,28 @@ unsafe impl<S: ?Sized + Storage> Storage for BoxStorage<S> {
     fn reserve(&mut self, new_capacity: usize) { S::reserve(&mut self.0, new_capacity); }
     #[inline]
     fn try_reserve(&mut self, new_capacity: usize) -> AllocResult { S::try_reserve(&mut self.0, new_capacity) }
+    #[inline]
+    fn heap_bytes(&self) -> usize { core::mem::size_of_val::<S>(&self.0) + S::heap_bytes(&self.0) }
+
+    #[doc(hidden)]
+    #[inline]
+    unsafe fn __drop_items(&mut self, range: Range<usize>) { unsafe { S::__drop_items(&mut self.0, range) } }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __release(&mut self, range: Range<usize>) { S::__release(&mut self.0, range) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __acquire(&mut self, range: Range<usize>) { S::__acquire(&mut self.0, range) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __set_len(&mut self, len: usize) { S::__set_len(&mut self.0, len) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __try_make_mut(&mut self) -> AllocResult { S::__try_make_mut(&mut self.0) }
 }
 
 #[cfg(any(doc, feature = "alloc"))]
@@ -1,5 +1,5 @@
 use crate::{
-    raw::{Storage, StorageWithCapacity},
+    raw::{FlattenStorage, Storage, StorageWithCapacity},
     uninit_array,
 };
 use core::mem::MaybeUninit;
@@ -15,6 +15,14 @@ unsafe impl<T, const N: usize> StorageWithCapacity for [MaybeUninit<T>; N] {
         uninit_array()
     }
 
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        if capacity <= N {
+            Ok(uninit_array())
+        } else {
+            Err(AllocError)
+        }
+    }
+
     #[inline]
     #[doc(hidden)]
     #[allow(non_snake_case)]
@@ -46,3 +54,91 @@ unsafe impl<T, const N: usize> Storage for [MaybeUninit<T>; N] {
         }
     }
 }
+
+/// An array storage of `N * M` items, created by flattening an array storage of `M` arrays of `N` items
+///
+/// `[MaybeUninit<T>; N * M]` can't be written with stable Rust, so this keeps the
+/// original `[MaybeUninit<[T; N]>; M]`, and presents it as a flat slice of items.
+/// See [`FlattenStorage`].
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{raw::FlatArray, ArrayVec, GenericVec};
+///
+/// let pixels = ArrayVec::<[u8; 3], 4>::from_partial_array([[1, 2, 3], [4, 5, 6]]);
+/// let mut bytes: GenericVec<u8, FlatArray<u8, 3, 4>> = pixels.into_flattened();
+/// assert_eq!(bytes, [1, 2, 3, 4, 5, 6]);
+/// assert_eq!(bytes.capacity(), 12);
+/// bytes.push(7);
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct FlatArray<T, const N: usize, const M: usize>([MaybeUninit<[T; N]>; M]);
+
+impl<T, const N: usize, const M: usize> FlatArray<T, N, M> {
+    /// Create a new uninitialized storage
+    pub const fn new() -> Self { Self(uninit_array()) }
+}
+
+impl<T, const N: usize, const M: usize> Default for FlatArray<T, N, M> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const N: usize, const M: usize> AsRef<[MaybeUninit<T>]> for FlatArray<T, N, M> {
+    fn as_ref(&self) -> &[MaybeUninit<T>] {
+        // Safety: `[MaybeUninit<[T; N]>; M]` has the same layout as `N * M` `MaybeUninit<T>`
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), N * M) }
+    }
+}
+
+impl<T, const N: usize, const M: usize> AsMut<[MaybeUninit<T>]> for FlatArray<T, N, M> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        // Safety: `[MaybeUninit<[T; N]>; M]` has the same layout as `N * M` `MaybeUninit<T>`
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), N * M) }
+    }
+}
+
+unsafe impl<T, const N: usize, const M: usize> Storage for FlatArray<T, N, M> {
+    type Item = T;
+
+    #[doc(hidden)]
+    const CONST_CAPACITY: Option<usize> = Some(N * M);
+
+    fn reserve(&mut self, capacity: usize) {
+        if capacity > N * M {
+            crate::raw::capacity::fixed_capacity_reserve_error(N * M, capacity)
+        }
+    }
+
+    fn try_reserve(&mut self, capacity: usize) -> AllocResult {
+        if capacity <= N * M {
+            Ok(())
+        } else {
+            Err(AllocError)
+        }
+    }
+}
+
+unsafe impl<T, const N: usize, const M: usize> StorageWithCapacity for FlatArray<T, N, M> {
+    fn with_capacity(capacity: usize) -> Self {
+        if capacity > N * M {
+            crate::raw::capacity::fixed_capacity_reserve_error(N * M, capacity)
+        }
+
+        Self::new()
+    }
+
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        if capacity <= N * M {
+            Ok(Self::new())
+        } else {
+            Err(AllocError)
+        }
+    }
+}
+
+unsafe impl<T, const N: usize, const M: usize> FlattenStorage<T, N> for [MaybeUninit<[T; N]>; M] {
+    type Flattened = FlatArray<T, N, M>;
+
+    fn flatten(self) -> Self::Flattened { FlatArray(self) }
+}
@@ -0,0 +1,255 @@
+use crate::raw::{AllocError, AllocResult, Storage, StorageWithCapacity};
+
+use core::{
+    cell::Cell,
+    mem::{size_of, MaybeUninit},
+    ops::Range,
+};
+
+/// A storage wrapper that asserts that the inner storage upholds the
+/// contracts of [`Storage`] and [`StorageWithCapacity`]
+///
+/// This is meant for testing custom storages, every call is checked
+///
+/// * the capacity never changes outside of `reserve` and `try_reserve`
+/// * `reserve` and `try_reserve` never shrink the capacity
+/// * after `reserve`, or `try_reserve` returns `Ok`, the capacity is at least the requested capacity
+/// * after `try_reserve` returns `Err`, the buffer was not changed
+/// * `as_ref` always returns the same buffer as the previous call to `as_ref` or `as_mut`,
+///   unless the storage was reserved in between. Copy-on-write storages may move
+///   their buffer in `as_mut`, but not in `as_ref`.
+/// * `with_capacity` creates a storage with at least the requested capacity
+/// * storages with a constant capacity always have exactly that capacity
+/// * the vector only passes ranges that are in bounds to the storage
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{raw::Checked, GenericVec};
+///
+/// let mut vec = GenericVec::<i32, Checked<Box<[_]>>>::default();
+/// vec.extend([1, 2, 3]);
+/// vec.reserve(10);
+/// assert_eq!(vec, [1, 2, 3]);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics as soon as the inner storage breaks one of its contracts
+pub struct Checked<S: ?Sized + Storage> {
+    ptr: Cell<usize>,
+    offset: Cell<usize>,
+    capacity: Cell<usize>,
+    storage: S,
+}
+
+impl<S: Storage> Checked<S> {
+    /// Wrap a storage
+    pub fn new(storage: S) -> Self {
+        let checked = Self {
+            ptr: Cell::new(0),
+            offset: Cell::new(0),
+            capacity: Cell::new(0),
+            storage,
+        };
+        checked.record(checked.storage.as_ref());
+        checked.check_const_capacity();
+        checked
+    }
+
+    /// Unwrap the storage
+    pub fn into_inner(self) -> S { self.storage }
+
+    fn check_with_capacity(self, capacity: usize) -> Self {
+        assert!(
+            self.capacity.get() >= capacity,
+            "`with_capacity` created a storage with a capacity of {}, but {} was requested",
+            self.capacity.get(),
+            capacity
+        );
+        self
+    }
+}
+
+impl<T, const N: usize> crate::GenericVec<T, Checked<[MaybeUninit<T>; N]>> {
+    /// Create a new empty checked `ArrayVec`
+    pub fn new() -> Self { Self::with_storage(Checked::new(crate::uninit_array())) }
+}
+
+impl<S: ?Sized + Storage> Checked<S> {
+    /// Get a reference to the inner storage
+    pub fn get(&self) -> &S { &self.storage }
+
+    /// The offset of the buffer from the storage, which stays the same for
+    /// inline buffers when the storage is moved
+    fn offset_of(&self, ptr: usize) -> usize { ptr.wrapping_sub(core::ptr::addr_of!(self.storage).cast::<u8>() as usize) }
+
+    fn record(&self, buffer: &[MaybeUninit<S::Item>]) {
+        let ptr = buffer.as_ptr() as usize;
+        self.ptr.set(ptr);
+        self.offset.set(self.offset_of(ptr));
+        self.capacity.set(buffer.len());
+    }
+
+    fn check_ptr(&self, buffer: &[MaybeUninit<S::Item>], message: &str) {
+        let ptr = buffer.as_ptr() as usize;
+        assert!(ptr == self.ptr.get() || self.offset_of(ptr) == self.offset.get(), "{message}");
+    }
+
+    fn check_capacity(&self, buffer: &[MaybeUninit<S::Item>], call: &str) {
+        assert_eq!(
+            buffer.len(),
+            self.capacity.get(),
+            "The capacity of the storage changed outside of `reserve` (in `{call}`)"
+        );
+    }
+
+    fn check_const_capacity(&self) {
+        if let Some(capacity) = S::CONST_CAPACITY {
+            assert_eq!(
+                self.capacity.get(),
+                capacity,
+                "The capacity of the storage is not its constant capacity"
+            );
+        }
+    }
+
+    fn check_range(&self, range: &Range<usize>, call: &str) {
+        if size_of::<S::Item>() != 0 {
+            assert!(
+                range.start <= range.end && range.end <= self.capacity.get(),
+                "`{}` was called with the range {:?}, but the capacity is {}",
+                call,
+                range,
+                self.capacity.get()
+            );
+        }
+    }
+
+    fn check_reserve(&self, old_capacity: usize, new_capacity: usize, call: &str) {
+        let buffer = self.storage.as_ref();
+        assert!(
+            buffer.len() >= old_capacity,
+            "`{}` shrunk the capacity from {} to {}",
+            call,
+            old_capacity,
+            buffer.len()
+        );
+        assert!(
+            buffer.len() >= new_capacity,
+            "`{}` left a capacity of {}, but {} was requested",
+            call,
+            buffer.len(),
+            new_capacity
+        );
+        self.record(buffer);
+        self.check_const_capacity();
+    }
+}
+
+impl<S: ?Sized + Storage> AsRef<[MaybeUninit<S::Item>]> for Checked<S> {
+    fn as_ref(&self) -> &[MaybeUninit<S::Item>] {
+        let buffer = self.storage.as_ref();
+        self.check_capacity(buffer, "as_ref");
+        self.check_ptr(buffer, "The buffer of the storage moved outside of `reserve` or `as_mut`");
+        buffer
+    }
+}
+
+impl<S: ?Sized + Storage> AsMut<[MaybeUninit<S::Item>]> for Checked<S> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<S::Item>] {
+        let Self {
+            ptr,
+            offset,
+            capacity,
+            storage,
+        } = self;
+        let base = core::ptr::addr_of!(*storage).cast::<u8>() as usize;
+        let buffer = storage.as_mut();
+
+        assert_eq!(
+            buffer.len(),
+            capacity.get(),
+            "The capacity of the storage changed outside of `reserve` (in `as_mut`)"
+        );
+        ptr.set(buffer.as_ptr() as usize);
+        offset.set(ptr.get().wrapping_sub(base));
+        buffer
+    }
+}
+
+unsafe impl<S: ?Sized + Storage> Storage for Checked<S> {
+    type Item = S::Item;
+
+    #[doc(hidden)]
+    const CONST_CAPACITY: Option<usize> = S::CONST_CAPACITY;
+
+    fn reserve(&mut self, new_capacity: usize) {
+        let old_capacity = self.capacity.get();
+        self.check_capacity(self.storage.as_ref(), "reserve");
+        self.storage.reserve(new_capacity);
+        self.check_reserve(old_capacity, new_capacity, "reserve");
+    }
+
+    fn try_reserve(&mut self, new_capacity: usize) -> AllocResult {
+        let old_capacity = self.capacity.get();
+        self.check_capacity(self.storage.as_ref(), "try_reserve");
+        let result = self.storage.try_reserve(new_capacity);
+
+        if result.is_ok() {
+            self.check_reserve(old_capacity, new_capacity, "try_reserve");
+        } else {
+            let buffer = self.storage.as_ref();
+            self.check_capacity(buffer, "try_reserve");
+            self.check_ptr(buffer, "`try_reserve` failed, but moved the buffer");
+        }
+
+        result
+    }
+
+    fn heap_bytes(&self) -> usize { self.storage.heap_bytes() }
+
+    #[doc(hidden)]
+    unsafe fn __drop_items(&mut self, range: Range<usize>) {
+        self.check_range(&range, "__drop_items");
+        unsafe { self.storage.__drop_items(range) }
+    }
+
+    #[doc(hidden)]
+    fn __release(&mut self, range: Range<usize>) {
+        self.check_range(&range, "__release");
+        self.storage.__release(range);
+    }
+
+    #[doc(hidden)]
+    fn __acquire(&mut self, range: Range<usize>) {
+        self.check_range(&range, "__acquire");
+        self.storage.__acquire(range);
+    }
+
+    #[doc(hidden)]
+    fn __set_len(&mut self, len: usize) { self.storage.__set_len(len) }
+
+    #[doc(hidden)]
+    fn __try_make_mut(&mut self) -> AllocResult { self.storage.__try_make_mut() }
+}
+
+unsafe impl<S: StorageWithCapacity> StorageWithCapacity for Checked<S> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self::new(S::with_capacity(capacity)).check_with_capacity(capacity)
+    }
+
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        S::try_with_capacity(capacity).map(|storage| Self::new(storage).check_with_capacity(capacity))
+    }
+
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    fn __with_capacity__const_capacity_checked(capacity: usize, old_capacity: Option<usize>) -> Self {
+        Self::new(S::__with_capacity__const_capacity_checked(capacity, old_capacity)).check_with_capacity(capacity)
+    }
+}
+
+impl<S: Storage + Default> Default for Checked<S> {
+    fn default() -> Self { Self::new(S::default()) }
+}
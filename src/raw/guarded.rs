@@ -0,0 +1,240 @@
+use crate::raw::{AllocError, AllocResult, Storage, StorageWithCapacity};
+
+use core::{
+    mem::{align_of, size_of, MaybeUninit},
+    ptr::NonNull,
+};
+use std::alloc::{handle_alloc_error, Layout};
+
+/// Which end of a [`GuardedHeap`]'s buffer is placed against its guard page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GuardAlign {
+    /// The guard page is right before the buffer, so writes before the start of the buffer fault
+    Start,
+    /// The guard page is right after the buffer, so writes past the capacity fault
+    #[default]
+    End,
+}
+
+/// A debugging heap storage where each buffer is placed directly against an
+/// inaccessible guard page
+///
+/// Out of bounds accesses through raw pointers (for example from
+/// [`RawCursor`](crate::iter::RawCursor), [`spare_capacity_mut`](crate::GenericVec::spare_capacity_mut)
+/// or [`extend_from_slice_unchecked`](crate::GenericVec::extend_from_slice_unchecked))
+/// on the guarded end of the buffer fault immediately, instead of silently corrupting memory.
+///
+/// Every buffer is mapped separately with `mmap`, and grows with `mremap`, so
+/// this storage is only useful for debugging.
+///
+/// ```rust
+/// use cl_generic_vec::{raw::{GuardAlign, GuardedHeap}, GenericVec};
+///
+/// let mut vec = GenericVec::with_storage(GuardedHeap::new(GuardAlign::End));
+/// vec.extend_from_slice(&[1_u32, 2, 3]);
+///
+/// // the next byte after the buffer is on the guard page
+/// let end = vec.storage().as_ref().as_ptr_range().end as usize;
+/// assert_eq!(end % 4096, 0);
+/// ```
+pub struct GuardedHeap<T> {
+    map: *mut libc::c_void,
+    map_len: usize,
+    ptr: NonNull<MaybeUninit<T>>,
+    capacity: usize,
+    align: GuardAlign,
+}
+
+unsafe impl<T: Send> Send for GuardedHeap<T> {}
+unsafe impl<T: Sync> Sync for GuardedHeap<T> {}
+
+fn page_size() -> usize {
+    // Safety: `sysconf` has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    usize::try_from(size).expect("Could not get the page size")
+}
+
+impl<T> GuardedHeap<T> {
+    /// Create a new empty storage, which aligns its buffers as specified by `align`
+    pub fn new(align: GuardAlign) -> Self {
+        Self {
+            map: core::ptr::null_mut(),
+            map_len: 0,
+            ptr: NonNull::dangling(),
+            capacity: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+            align,
+        }
+    }
+
+    /// Create a new storage with the given capacity, which aligns its buffers as specified by `align`
+    ///
+    /// # Panics
+    ///
+    /// May panic or abort if it isn't possible to map a buffer with enough space
+    pub fn with_capacity_aligned(capacity: usize, align: GuardAlign) -> Self {
+        let mut storage = Self::new(align);
+        if storage.capacity < capacity {
+            storage.remap_or_abort(capacity);
+        }
+        storage
+    }
+
+    /// Which end of the buffer is placed against the guard page
+    pub fn guard_align(&self) -> GuardAlign { self.align }
+
+    /// The offsets of the guard page and the buffer in a mapping of `map_len` bytes,
+    /// which holds `bytes` bytes of items
+    fn offsets(&self, map_len: usize, bytes: usize, page: usize) -> (usize, usize) {
+        match self.align {
+            GuardAlign::Start => (0, page),
+            GuardAlign::End => (map_len - page, map_len - page - bytes),
+        }
+    }
+
+    fn remap_or_abort(&mut self, capacity: usize) {
+        if self.remap(capacity).is_err() {
+            handle_alloc_error(Layout::array::<T>(capacity).unwrap_or_else(|_| Layout::new::<T>()))
+        }
+    }
+
+    /// Moves the buffer into a mapping that can hold exactly `capacity` items
+    fn remap(&mut self, capacity: usize) -> AllocResult {
+        let page = page_size();
+        if align_of::<T>() > page {
+            return Err(AllocError)
+        }
+
+        let bytes = capacity.checked_mul(size_of::<T>()).ok_or(AllocError)?;
+        let map_len = bytes
+            .checked_add(page - 1)
+            .map(|bytes| bytes & !(page - 1))
+            .and_then(|data| data.checked_add(page))
+            .ok_or(AllocError)?;
+
+        let old_bytes = if self.map.is_null() { 0 } else { self.capacity * size_of::<T>() };
+        let (old_guard, old_offset) = if self.map.is_null() {
+            (0, 0)
+        } else {
+            self.offsets(self.map_len, old_bytes, page)
+        };
+        let (guard, offset) = self.offsets(map_len, bytes, page);
+
+        // Safety
+        //
+        // * `self.map` is either null, or a mapping of `self.map_len` bytes,
+        //   that has a guard page at `old_guard`
+        // * the old buffer is moved to the new location before the guard page is set up
+        unsafe {
+            let map = if self.map.is_null() {
+                libc::mmap(
+                    core::ptr::null_mut(),
+                    map_len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            } else {
+                // the guard page needs to be accessible so that the mapping
+                // is a single region that can be remapped
+                let old_guard = self.map.cast::<u8>().add(old_guard).cast();
+                if libc::mprotect(old_guard, page, libc::PROT_READ | libc::PROT_WRITE) != 0 {
+                    return Err(AllocError)
+                }
+
+                let map = libc::mremap(self.map, self.map_len, map_len, libc::MREMAP_MAYMOVE);
+
+                if map == libc::MAP_FAILED {
+                    libc::mprotect(old_guard, page, libc::PROT_NONE);
+                }
+
+                map
+            };
+
+            if map == libc::MAP_FAILED {
+                return Err(AllocError)
+            }
+
+            let base = map.cast::<u8>();
+            if old_offset != offset {
+                base.add(offset).copy_from(base.add(old_offset), old_bytes);
+            }
+
+            self.map = map;
+            self.map_len = map_len;
+            self.ptr = NonNull::new_unchecked(base.add(offset).cast());
+            self.capacity = capacity;
+
+            if libc::mprotect(base.add(guard).cast(), page, libc::PROT_NONE) != 0 {
+                return Err(AllocError)
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Drop for GuardedHeap<T> {
+    fn drop(&mut self) {
+        if !self.map.is_null() {
+            // Safety: `self.map` is a mapping of `self.map_len` bytes
+            unsafe { libc::munmap(self.map, self.map_len) };
+        }
+    }
+}
+
+impl<T> Default for GuardedHeap<T> {
+    fn default() -> Self { Self::new(GuardAlign::default()) }
+}
+
+impl<T> AsRef<[MaybeUninit<T>]> for GuardedHeap<T> {
+    fn as_ref(&self) -> &[MaybeUninit<T>] {
+        // Safety: the buffer holds `capacity` slots
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.capacity) }
+    }
+}
+
+impl<T> AsMut<[MaybeUninit<T>]> for GuardedHeap<T> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        // Safety: the buffer holds `capacity` slots
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.capacity) }
+    }
+}
+
+unsafe impl<T> Storage for GuardedHeap<T> {
+    type Item = T;
+
+    fn reserve(&mut self, new_capacity: usize) {
+        if self.capacity < new_capacity {
+            let new_capacity = new_capacity
+                .max(self.capacity.saturating_mul(2))
+                .max(super::heap::INIT_ALLOC_CAPACITY);
+            self.remap_or_abort(new_capacity);
+        }
+    }
+
+    fn try_reserve(&mut self, new_capacity: usize) -> AllocResult {
+        if self.capacity < new_capacity {
+            let new_capacity = new_capacity
+                .max(self.capacity.saturating_mul(2))
+                .max(super::heap::INIT_ALLOC_CAPACITY);
+            self.remap(new_capacity)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn heap_bytes(&self) -> usize { self.map_len }
+}
+
+unsafe impl<T> StorageWithCapacity for GuardedHeap<T> {
+    fn with_capacity(capacity: usize) -> Self { Self::with_capacity_aligned(capacity, GuardAlign::default()) }
+
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        let mut storage = Self::default();
+        if storage.capacity < capacity {
+            storage.remap(capacity)?;
+        }
+        Ok(storage)
+    }
+}
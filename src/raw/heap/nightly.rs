@@ -1,10 +1,11 @@
-use crate::raw::{AllocError, AllocResult, Storage, StorageWithCapacity};
+use crate::raw::{AllocError, AllocResult, FlattenStorage, Storage, StorageWithCapacity};
 
 use core::{alloc::Layout, ptr::NonNull};
 use std::{alloc::handle_alloc_error, mem::MaybeUninit};
 
 use std::alloc::Allocator;
 
+#[derive(Clone, Copy)]
 enum OnFailure {
     Abort,
     Error,
@@ -40,6 +41,19 @@ pub(crate) fn box_into_raw_parts_with_alloc<T, A: Allocator>(b: Heap<T, A>) -> (
     }
 }
 
+unsafe impl<T, A: Allocator, const N: usize> FlattenStorage<T, N> for Heap<[T; N], A> {
+    type Flattened = Heap<T, A>;
+
+    fn flatten(self) -> Self::Flattened {
+        let (ptr, capacity, alloc) = box_into_raw_parts_with_alloc(self);
+        // Safety
+        //
+        // `[T; N]` has the same alignment as `T`, so the buffer has the same layout as
+        // a buffer of `N` times as many `T`s. Zero sized buffers are never deallocated.
+        unsafe { box_from_raw_parts_in(ptr.cast(), capacity.saturating_mul(N), alloc) }
+    }
+}
+
 unsafe impl<T, A: Allocator> Storage for Heap<T, A> {
     type Item = T;
 
@@ -56,10 +70,16 @@ unsafe impl<T, A: Allocator> Storage for Heap<T, A> {
             Ok(())
         }
     }
+
+    fn heap_bytes(&self) -> usize { core::mem::size_of_val::<[MaybeUninit<T>]>(self) }
 }
 
 unsafe impl<T, A: Default + Allocator> StorageWithCapacity for Heap<T, A> {
     fn with_capacity(cap: usize) -> Self { Box::new_uninit_slice_in(cap, A::default()) }
+
+    fn try_with_capacity(cap: usize) -> Result<Self, AllocError> {
+        Box::try_new_uninit_slice_in(cap, A::default()).map_err(|_| AllocError)
+    }
 }
 
 #[cold]
@@ -67,30 +87,37 @@ unsafe impl<T, A: Default + Allocator> StorageWithCapacity for Heap<T, A> {
 fn reserve_slow<T, A: Allocator>(b: &mut Heap<T, A>, new_capacity: usize, on_failure: OnFailure) -> AllocResult {
     assert!(new_capacity > b.len());
 
+    // grow by at least doubling
+    let new_capacity = new_capacity.max(b.len().saturating_mul(2)).max(super::INIT_ALLOC_CAPACITY);
+    let layout = match (Layout::array::<T>(new_capacity), on_failure) {
+        (Ok(layout), _) => layout,
+        (Err(_), OnFailure::Abort) => panic!("Tried to allocate {} items, which is too large", new_capacity),
+        (Err(_), OnFailure::Error) => return Err(AllocError),
+    };
+
     // taking a copy of the box so we can get it's contents and then update it later
     // Safety:
     // we forget the box just as soon we we copy it, so we have no risk of double-free
     let (ptr, cap, alloc) = unsafe { box_into_raw_parts_with_alloc(std::ptr::read(b)) };
 
-    // grow by at least doubling
-    let new_capacity = new_capacity
-        .max(cap.checked_mul(2).expect("Could not grow further"))
-        .max(super::INIT_ALLOC_CAPACITY);
-    let layout = Layout::new::<T>().repeat(new_capacity).expect("Invalid layout").0;
-
     let ptr = if cap == 0 {
-        unsafe { alloc.allocate(layout) }
+        alloc.allocate(layout)
     } else {
-        let new_layout = layout;
-        let old_layout = Layout::new::<T>().repeat(cap).expect("Invalid layout").0;
+        // Safety: the old buffer was allocated with this layout, so it is valid
+        let old_layout =
+            unsafe { Layout::from_size_align_unchecked(cap * core::mem::size_of::<T>(), core::mem::align_of::<T>()) };
 
-        unsafe { alloc.grow(ptr.cast(), old_layout, new_layout) }
+        unsafe { alloc.grow(ptr.cast(), old_layout, layout) }
     };
 
     let ptr = match (ptr, on_failure) {
         (Ok(ptr), _) => ptr,
         (Err(_), OnFailure::Abort) => handle_alloc_error(layout),
-        (Err(_), OnFailure::Error) => return Err(AllocError),
+        (Err(_), OnFailure::Error) => {
+            // `b` still owns the allocator
+            core::mem::forget(alloc);
+            return Err(AllocError)
+        }
     };
 
     // Creating a new Heap using the re-alloced pointer.
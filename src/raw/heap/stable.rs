@@ -1,4 +1,4 @@
-use crate::raw::{AllocError, AllocResult, Storage, StorageWithCapacity};
+use crate::raw::{AllocError, AllocResult, FlattenStorage, Storage, StorageWithCapacity};
 
 use core::alloc::Layout;
 use std::{
@@ -9,6 +9,7 @@ use std::{
 
 type Heap<T> = Box<[MaybeUninit<T>]>;
 
+#[derive(Clone, Copy)]
 enum OnFailure {
     Abort,
     Error,
@@ -31,11 +32,24 @@ pub(crate) unsafe fn box_from_raw_parts<T>(ptr: NonNull<T>, capacity: usize) ->
 pub(crate) fn box_into_raw_parts<T>(b: Heap<T>) -> (NonNull<T>, usize) {
     let ptr = Box::into_raw(b);
     unsafe {
-        let capacity = (*ptr).len(); // probably not great but ptr_metadata is still nightly
+        let capacity = (&*ptr).len(); // probably not great but ptr_metadata is still nightly
         (NonNull::new_unchecked(ptr.cast()), capacity)
     }
 }
 
+unsafe impl<T, const N: usize> FlattenStorage<T, N> for Heap<[T; N]> {
+    type Flattened = Heap<T>;
+
+    fn flatten(self) -> Self::Flattened {
+        let (ptr, capacity) = box_into_raw_parts(self);
+        // Safety
+        //
+        // `[T; N]` has the same alignment as `T`, so the buffer has the same layout as
+        // a buffer of `N` times as many `T`s. Zero sized buffers are never deallocated.
+        unsafe { box_from_raw_parts(ptr.cast(), capacity.saturating_mul(N)) }
+    }
+}
+
 unsafe impl<T> Storage for Heap<T> {
     type Item = T;
 
@@ -52,68 +66,48 @@ unsafe impl<T> Storage for Heap<T> {
             Ok(())
         }
     }
-}
 
-pub fn padding_needed_for(layout: Layout, align: usize) -> usize {
-    let len = layout.size();
-
-    // Rounded up value is:
-    //   len_rounded_up = (len + align - 1) & !(align - 1);
-    // and then we return the padding difference: `len_rounded_up - len`.
-    //
-    // We use modular arithmetic throughout:
-    //
-    // 1. align is guaranteed to be > 0, so align - 1 is always
-    //    valid.
-    //
-    // 2. `len + align - 1` can overflow by at most `align - 1`,
-    //    so the &-mask with `!(align - 1)` will ensure that in the
-    //    case of overflow, `len_rounded_up` will itself be 0.
-    //    Thus the returned padding, when added to `len`, yields 0,
-    //    which trivially satisfies the alignment `align`.
-    //
-    // (Of course, attempts to allocate blocks of memory whose
-    // size and padding overflow in the above manner should cause
-    // the allocator to yield an error anyway.)
-
-    let len_rounded_up = len.wrapping_add(align).wrapping_sub(1) & !align.wrapping_sub(1);
-    len_rounded_up.wrapping_sub(len)
+    fn heap_bytes(&self) -> usize { core::mem::size_of_val::<[MaybeUninit<T>]>(self) }
 }
 
-pub fn repeat(layout: Layout, n: usize) -> Result<Layout, ()> {
-    // This cannot overflow. Quoting from the invariant of Layout:
-    // > `size`, when rounded up to the nearest multiple of `align`,
-    // > must not overflow (i.e., the rounded value must be less than
-    // > `usize::MAX`)
-    let padded_size = layout.size() + padding_needed_for(layout, layout.align());
-    let alloc_size = padded_size.checked_mul(n).ok_or(())?;
-
-    // SAFETY: self.align is already known to be valid and alloc_size has been
-    // padded already.
-    unsafe { Ok(Layout::from_size_align_unchecked(alloc_size, layout.align())) }
+/// The layout of a buffer of `capacity` items
+fn layout<T>(capacity: usize, on_failure: OnFailure) -> Result<Layout, AllocError> {
+    match (Layout::array::<T>(capacity), on_failure) {
+        (Ok(layout), _) => Ok(layout),
+        (Err(_), OnFailure::Abort) => panic!("Tried to allocate {capacity} items, which is too large"),
+        (Err(_), OnFailure::Error) => Err(AllocError),
+    }
 }
 
-fn box_with_capacity<T>(capacity: usize) -> Heap<T> {
-    if core::mem::size_of::<T>() == 0 {
-        return Box::default()
+fn box_with_capacity<T>(capacity: usize, on_failure: OnFailure) -> Result<Heap<T>, AllocError> {
+    if core::mem::size_of::<T>() == 0 || capacity == 0 {
+        return Ok(Box::default())
     }
 
-    let layout = repeat(Layout::new::<T>(), capacity).expect("Invalid layout");
+    let layout = layout::<T>(capacity, on_failure)?;
 
     let ptr = unsafe { alloc(layout) };
 
-    let ptr = match core::ptr::NonNull::new(ptr) {
-        Some(ptr) => ptr,
-        None => handle_alloc_error(layout),
+    let ptr = match (core::ptr::NonNull::new(ptr), on_failure) {
+        (Some(ptr), _) => ptr,
+        (None, OnFailure::Abort) => handle_alloc_error(layout),
+        (None, OnFailure::Error) => return Err(AllocError),
     };
 
     // Safety:
     // we have allocated a pointer in global that has `capacity` elements available
-    unsafe { box_from_raw_parts(ptr.cast(), capacity) }
+    unsafe { Ok(box_from_raw_parts(ptr.cast(), capacity)) }
 }
 
 unsafe impl<T> StorageWithCapacity for Heap<T> {
-    fn with_capacity(cap: usize) -> Self { box_with_capacity(cap) }
+    fn with_capacity(cap: usize) -> Self {
+        match box_with_capacity(cap, OnFailure::Abort) {
+            Ok(heap) => heap,
+            Err(AllocError) => unreachable!("allocation failures abort"),
+        }
+    }
+
+    fn try_with_capacity(cap: usize) -> Result<Self, AllocError> { box_with_capacity(cap, OnFailure::Error) }
 }
 
 #[cold]
@@ -128,15 +122,18 @@ fn reserve_slow<T>(b: &mut Heap<T>, new_capacity: usize, on_failure: OnFailure)
 
     // grow by at least doubling
     let new_capacity = new_capacity
-        .max(cap.checked_mul(2).expect("Could not grow further"))
+        .max(cap.saturating_mul(2))
         .max(super::INIT_ALLOC_CAPACITY);
-    let layout = repeat(Layout::new::<T>(), new_capacity).expect("Invalid layout");
+    let layout = layout::<T>(new_capacity, on_failure)?;
 
     let ptr = if cap == 0 {
         unsafe { alloc(layout) }
     } else {
         let new_layout = layout;
-        let old_layout = repeat(Layout::new::<T>(), cap).expect("Invalid layout");
+        // Safety: the old buffer was allocated with this layout, so it is valid
+        let old_layout = unsafe {
+            Layout::from_size_align_unchecked(cap * core::mem::size_of::<T>(), core::mem::align_of::<T>())
+        };
 
         unsafe { realloc(ptr.as_ptr().cast(), old_layout, new_layout.size()) }
     };
@@ -0,0 +1,290 @@
+use crate::raw::{AllocError, AllocResult, Storage, StorageWithCapacity};
+
+use core::{
+    mem::{size_of, MaybeUninit},
+    ops::Range,
+};
+
+/// Statistics collected by an [`Instrumented`] storage
+///
+/// Bytes are only counted for buffers that were allocated while the storage
+/// was instrumented, so the buffer of a storage passed to [`Instrumented::new`]
+/// is not counted as allocated or freed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of calls to [`Storage::reserve`]
+    pub reserves: usize,
+    /// The number of calls to [`Storage::try_reserve`]
+    pub try_reserves: usize,
+    /// The number of times the capacity of the storage changed
+    pub reallocations: usize,
+    /// The number of failed calls to [`Storage::try_reserve`]
+    pub failed_reservations: usize,
+    /// The total size of all buffers that were allocated, in bytes
+    pub bytes_allocated: usize,
+    /// The total size of all buffers that were freed, in bytes
+    pub bytes_freed: usize,
+    /// The largest capacity that the storage ever had
+    pub max_capacity: usize,
+    /// The largest length that the vector ever had
+    pub max_len: usize,
+}
+
+impl Stats {
+    /// Add the statistics of another storage to these statistics
+    ///
+    /// Counters are summed, and the maximums are the larger of both maximums
+    pub fn merge(&mut self, other: &Self) {
+        self.reserves += other.reserves;
+        self.try_reserves += other.try_reserves;
+        self.reallocations += other.reallocations;
+        self.failed_reservations += other.failed_reservations;
+        self.bytes_allocated += other.bytes_allocated;
+        self.bytes_freed += other.bytes_freed;
+        self.max_capacity = self.max_capacity.max(other.max_capacity);
+        self.max_len = self.max_len.max(other.max_len);
+    }
+}
+
+/// A destination for the [`Stats`] of [`Instrumented`] storages
+///
+/// Each storage reports its statistics once, when it is dropped.
+/// The unit type discards all statistics.
+pub trait StatsSink {
+    /// Receive the statistics of a storage that is being dropped
+    fn report(&self, stats: &Stats);
+}
+
+impl StatsSink for () {
+    fn report(&self, _: &Stats) {}
+}
+
+impl<K: ?Sized + StatsSink> StatsSink for &K {
+    fn report(&self, stats: &Stats) { K::report(self, stats) }
+}
+
+/// A [`StatsSink`] that aggregates the statistics of many storages
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{raw::{Instrumented, SharedStats}, GenericVec};
+///
+/// static STATS: SharedStats = SharedStats::new();
+///
+/// for len in 0..10 {
+///     let mut vec = GenericVec::with_storage(Instrumented::with_sink(Box::<[_]>::default(), &STATS));
+///     vec.extend(0..len);
+/// }
+///
+/// let stats = STATS.snapshot();
+/// assert_eq!(stats.max_len, 9);
+/// assert_eq!(stats.bytes_allocated, stats.bytes_freed);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+#[derive(Debug, Default)]
+pub struct SharedStats(std::sync::Mutex<Stats>);
+
+#[cfg(feature = "std")]
+impl SharedStats {
+    /// Create a new sink, without any statistics
+    pub const fn new() -> Self {
+        Self(std::sync::Mutex::new(Stats {
+            reserves: 0,
+            try_reserves: 0,
+            reallocations: 0,
+            failed_reservations: 0,
+            bytes_allocated: 0,
+            bytes_freed: 0,
+            max_capacity: 0,
+            max_len: 0,
+        }))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Stats> { self.0.lock().unwrap_or_else(std::sync::PoisonError::into_inner) }
+
+    /// The statistics of all storages that have reported so far
+    pub fn snapshot(&self) -> Stats { *self.lock() }
+
+    /// Forget all statistics that were reported so far
+    pub fn reset(&self) { *self.lock() = Stats::default(); }
+}
+
+#[cfg(feature = "std")]
+impl StatsSink for SharedStats {
+    fn report(&self, stats: &Stats) { self.lock().merge(stats) }
+}
+
+/// A storage wrapper that records [`Stats`] about how the inner storage is used
+///
+/// The statistics of a single storage can be read with [`Instrumented::stats`].
+/// When the storage is dropped, its statistics are reported to its [`StatsSink`],
+/// which can aggregate the statistics of many vectors. With `std`, [`SharedStats`]
+/// can be used as a sink, in `no_std` you can implement [`StatsSink`] yourself.
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{raw::Instrumented, GenericVec};
+///
+/// let mut vec = GenericVec::<u32, Instrumented<Box<[_]>>>::default();
+/// vec.extend_from_slice(&[1, 2, 3]);
+/// vec.truncate(1);
+///
+/// let stats = vec.storage().stats();
+/// assert_eq!(stats.max_len, 3);
+/// assert_eq!(stats.reallocations, 1);
+/// assert_eq!(stats.bytes_allocated, 4 * stats.max_capacity);
+/// # }
+/// ```
+pub struct Instrumented<S: ?Sized + Storage, K: StatsSink = ()> {
+    stats: Stats,
+    // the number of bytes in the current buffer that were counted as allocated
+    owned_bytes: usize,
+    sink: K,
+    storage: S,
+}
+
+impl<S: Storage> Instrumented<S> {
+    /// Wrap a storage, and discard its statistics when it is dropped
+    pub fn new(storage: S) -> Self { Self::with_sink(storage, ()) }
+}
+
+impl<S: Storage, K: StatsSink> Instrumented<S, K> {
+    /// Wrap a storage, and report its statistics to `sink` when it is dropped
+    pub fn with_sink(storage: S, sink: K) -> Self {
+        let mut instrumented = Self {
+            stats: Stats::default(),
+            owned_bytes: 0,
+            sink,
+            storage,
+        };
+        instrumented.stats.max_capacity = instrumented.capacity();
+        instrumented
+    }
+
+    /// Counts the buffer of a newly created storage as allocated
+    fn allocated(mut self) -> Self {
+        if S::CONST_CAPACITY.is_none() {
+            self.owned_bytes = self.capacity() * size_of::<S::Item>();
+            self.stats.bytes_allocated = self.owned_bytes;
+        }
+        self
+    }
+}
+
+impl<T, const N: usize> crate::GenericVec<T, Instrumented<[MaybeUninit<T>; N]>> {
+    /// Create a new empty instrumented `ArrayVec`
+    pub fn new() -> Self { Self::with_storage(Instrumented::new(crate::uninit_array())) }
+}
+
+impl<S: ?Sized + Storage, K: StatsSink> Instrumented<S, K> {
+    /// A snapshot of the statistics of this storage
+    pub fn stats(&self) -> Stats { self.stats }
+
+    /// Get a reference to the sink
+    pub fn sink(&self) -> &K { &self.sink }
+
+    /// Get a reference to the inner storage
+    pub fn get(&self) -> &S { &self.storage }
+
+    fn capacity(&self) -> usize { self.storage.as_ref().len() }
+
+    fn record_reserve(&mut self, old_capacity: usize) {
+        let capacity = self.capacity();
+
+        if capacity != old_capacity {
+            let bytes = capacity * size_of::<S::Item>();
+            self.stats.reallocations += 1;
+            self.stats.bytes_allocated += bytes;
+            self.stats.bytes_freed += self.owned_bytes;
+            self.owned_bytes = bytes;
+        }
+
+        self.stats.max_capacity = self.stats.max_capacity.max(capacity);
+    }
+}
+
+impl<S: ?Sized + Storage, K: StatsSink> Drop for Instrumented<S, K> {
+    fn drop(&mut self) {
+        self.stats.bytes_freed += core::mem::take(&mut self.owned_bytes);
+        self.sink.report(&self.stats);
+    }
+}
+
+impl<S: ?Sized + Storage, K: StatsSink> AsRef<[MaybeUninit<S::Item>]> for Instrumented<S, K> {
+    fn as_ref(&self) -> &[MaybeUninit<S::Item>] { self.storage.as_ref() }
+}
+
+impl<S: ?Sized + Storage, K: StatsSink> AsMut<[MaybeUninit<S::Item>]> for Instrumented<S, K> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<S::Item>] { self.storage.as_mut() }
+}
+
+unsafe impl<S: ?Sized + Storage, K: StatsSink> Storage for Instrumented<S, K> {
+    type Item = S::Item;
+
+    #[doc(hidden)]
+    const CONST_CAPACITY: Option<usize> = S::CONST_CAPACITY;
+
+    fn reserve(&mut self, new_capacity: usize) {
+        let old_capacity = self.capacity();
+        self.stats.reserves += 1;
+        self.storage.reserve(new_capacity);
+        self.record_reserve(old_capacity);
+    }
+
+    fn try_reserve(&mut self, new_capacity: usize) -> AllocResult {
+        let old_capacity = self.capacity();
+        self.stats.try_reserves += 1;
+        let result = self.storage.try_reserve(new_capacity);
+
+        if result.is_err() {
+            self.stats.failed_reservations += 1;
+        }
+
+        self.record_reserve(old_capacity);
+        result
+    }
+
+    fn heap_bytes(&self) -> usize { self.storage.heap_bytes() }
+
+    #[doc(hidden)]
+    unsafe fn __drop_items(&mut self, range: Range<usize>) { unsafe { self.storage.__drop_items(range) } }
+
+    #[doc(hidden)]
+    fn __release(&mut self, range: Range<usize>) { self.storage.__release(range) }
+
+    #[doc(hidden)]
+    fn __acquire(&mut self, range: Range<usize>) { self.storage.__acquire(range) }
+
+    #[doc(hidden)]
+    fn __set_len(&mut self, len: usize) {
+        self.stats.max_len = self.stats.max_len.max(len);
+        self.storage.__set_len(len);
+    }
+
+    #[doc(hidden)]
+    fn __try_make_mut(&mut self) -> AllocResult { self.storage.__try_make_mut() }
+}
+
+unsafe impl<S: StorageWithCapacity, K: StatsSink + Default> StorageWithCapacity for Instrumented<S, K> {
+    fn with_capacity(capacity: usize) -> Self { Self::with_sink(S::with_capacity(capacity), K::default()).allocated() }
+
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        S::try_with_capacity(capacity).map(|storage| Self::with_sink(storage, K::default()).allocated())
+    }
+
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    fn __with_capacity__const_capacity_checked(capacity: usize, old_capacity: Option<usize>) -> Self {
+        Self::with_sink(
+            S::__with_capacity__const_capacity_checked(capacity, old_capacity),
+            K::default(),
+        )
+        .allocated()
+    }
+}
+
+impl<S: Storage + Default, K: StatsSink + Default> Default for Instrumented<S, K> {
+    fn default() -> Self { Self::with_sink(S::default(), K::default()) }
+}
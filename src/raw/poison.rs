@@ -0,0 +1,170 @@
+use crate::raw::{AllocError, AllocResult, FlattenStorage, Storage, StorageWithCapacity};
+
+use core::{
+    mem::{size_of, MaybeUninit},
+    ops::Range,
+};
+
+/// A debugging storage wrapper that fills every slot that doesn't hold an item
+/// with [`Poison::BYTE`]
+///
+/// Slots are poisoned when
+///
+/// * the storage is created, or grown (only the new slots are poisoned)
+/// * items are removed from the vector (for example by
+///   [`truncate`](crate::GenericVec::truncate), [`pop`](crate::GenericVec::pop),
+///   [`drain`](crate::GenericVec::drain) or [`split_off_into`](crate::GenericVec::split_off_into))
+///
+/// This makes reads of uninitialized or freed slots easy to spot in a debugger or
+/// in a memory dump. If `VERIFY` is `true`, then the vector also checks that
+/// the poison is still intact right before it writes items into those slots,
+/// which catches writes through dangling pointers into the spare capacity.
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{raw::Poison, GenericVec};
+/// use core::mem::MaybeUninit;
+///
+/// type Storage = Poison<[MaybeUninit<u8>; 8], true>;
+///
+/// let mut vec = GenericVec::<u8, Storage>::new();
+/// vec.extend_from_slice(&[1, 2, 3, 4]);
+/// vec.truncate(1);
+/// assert!(vec.storage().as_ref()[1..].iter().all(|b| unsafe { b.assume_init() } == Storage::BYTE));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// If `VERIFY` is `true`, writing an item into a slot whose poison was overwritten panics
+#[repr(transparent)]
+pub struct Poison<S: ?Sized + Storage, const VERIFY: bool = false>(S);
+
+impl<S: ?Sized + Storage, const VERIFY: bool> Poison<S, VERIFY> {
+    /// The byte that every slot without an item is filled with
+    pub const BYTE: u8 = 0xA5;
+
+    /// Fill the slots in `range` with poison
+    fn poison(&mut self, range: Range<usize>) {
+        if size_of::<S::Item>() != 0 {
+            let slots = &mut self.0.as_mut()[range];
+            // Safety: the slots are valid to write
+            unsafe { slots.as_mut_ptr().write_bytes(Self::BYTE, slots.len()) }
+        }
+    }
+
+    /// Checks that the slots in `range` are still filled with poison
+    fn verify(&self, range: Range<usize>) {
+        if size_of::<S::Item>() == 0 || range.start >= range.end {
+            return
+        }
+
+        let slots = &self.0.as_ref()[range.clone()];
+        let bytes = slots.as_ptr().cast::<u8>();
+
+        for i in 0..size_of::<S::Item>() * slots.len() {
+            // Safety: every byte of the poisoned slots was initialized with `BYTE`
+            let byte = unsafe { bytes.add(i).read_volatile() };
+            assert!(
+                byte == Self::BYTE,
+                "The unused slot {} of a `Poison` storage was written to",
+                range.start + i / size_of::<S::Item>()
+            );
+        }
+    }
+}
+
+impl<S: Storage, const VERIFY: bool> Poison<S, VERIFY> {
+    /// Wrap a storage, and poison all of its slots
+    ///
+    /// The storage must not contain any items
+    pub fn new(storage: S) -> Self {
+        let mut poison = Self(storage);
+        let capacity = poison.0.as_ref().len();
+        poison.poison(0..capacity);
+        poison
+    }
+}
+
+impl<T, const N: usize, const VERIFY: bool> crate::GenericVec<T, Poison<[MaybeUninit<T>; N], VERIFY>> {
+    /// Create a new empty poisoned `ArrayVec`
+    pub fn new() -> Self { Self::with_storage(Poison::new(crate::uninit_array())) }
+}
+
+impl<S: ?Sized + Storage, const VERIFY: bool> AsRef<[MaybeUninit<S::Item>]> for Poison<S, VERIFY> {
+    fn as_ref(&self) -> &[MaybeUninit<S::Item>] { self.0.as_ref() }
+}
+
+impl<S: ?Sized + Storage, const VERIFY: bool> AsMut<[MaybeUninit<S::Item>]> for Poison<S, VERIFY> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<S::Item>] { self.0.as_mut() }
+}
+
+unsafe impl<S: ?Sized + Storage, const VERIFY: bool> Storage for Poison<S, VERIFY> {
+    type Item = S::Item;
+
+    #[doc(hidden)]
+    const CONST_CAPACITY: Option<usize> = S::CONST_CAPACITY;
+
+    fn reserve(&mut self, new_capacity: usize) {
+        let capacity = self.0.as_ref().len();
+        self.0.reserve(new_capacity);
+        let new_capacity = self.0.as_ref().len();
+        self.poison(capacity..new_capacity);
+    }
+
+    fn try_reserve(&mut self, new_capacity: usize) -> AllocResult {
+        let capacity = self.0.as_ref().len();
+        self.0.try_reserve(new_capacity)?;
+        let new_capacity = self.0.as_ref().len();
+        self.poison(capacity..new_capacity);
+        Ok(())
+    }
+
+    fn heap_bytes(&self) -> usize { self.0.heap_bytes() }
+
+    #[doc(hidden)]
+    unsafe fn __drop_items(&mut self, range: Range<usize>) { unsafe { self.0.__drop_items(range) } }
+
+    #[doc(hidden)]
+    fn __release(&mut self, range: Range<usize>) {
+        self.0.__release(range.clone());
+        self.poison(range);
+    }
+
+    #[doc(hidden)]
+    fn __acquire(&mut self, range: Range<usize>) {
+        if VERIFY {
+            self.verify(range.clone());
+        }
+        self.0.__acquire(range);
+    }
+
+    #[doc(hidden)]
+    fn __set_len(&mut self, len: usize) { self.0.__set_len(len) }
+
+    #[doc(hidden)]
+    fn __try_make_mut(&mut self) -> AllocResult { self.0.__try_make_mut() }
+}
+
+unsafe impl<S: StorageWithCapacity, const VERIFY: bool> StorageWithCapacity for Poison<S, VERIFY> {
+    fn with_capacity(capacity: usize) -> Self { Self::new(S::with_capacity(capacity)) }
+
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> { S::try_with_capacity(capacity).map(Self::new) }
+
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    fn __with_capacity__const_capacity_checked(capacity: usize, old_capacity: Option<usize>) -> Self {
+        Self::new(S::__with_capacity__const_capacity_checked(capacity, old_capacity))
+    }
+}
+
+impl<S: Storage + Default, const VERIFY: bool> Default for Poison<S, VERIFY> {
+    fn default() -> Self { Self::new(S::default()) }
+}
+
+unsafe impl<T, S: FlattenStorage<T, N>, const N: usize, const VERIFY: bool> FlattenStorage<T, N> for Poison<S, VERIFY> {
+    type Flattened = Poison<S::Flattened, VERIFY>;
+
+    // the poison is kept, because the slots don't move
+    fn flatten(self) -> Self::Flattened { Poison(self.0.flatten()) }
+}
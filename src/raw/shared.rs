@@ -0,0 +1,234 @@
+use crate::{
+    raw::{AllocError, AllocResult, Storage},
+    HeapVec,
+};
+
+use core::{
+    alloc::Layout,
+    cell::Cell,
+    mem::MaybeUninit,
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{boxed::Box, rc::Rc, sync::Arc};
+
+type Heap<T> = Box<[MaybeUninit<T>]>;
+
+/// The number of initialized items in a shared buffer
+///
+/// This is only kept up to date while the buffer is shared,
+/// a unique owner keeps track of its own length
+trait SharedLen {
+    fn get(&self) -> usize;
+
+    fn set(&self, len: usize);
+
+    fn get_mut(&mut self) -> &mut usize;
+}
+
+impl SharedLen for Cell<usize> {
+    fn get(&self) -> usize { Cell::get(self) }
+
+    fn set(&self, len: usize) { Cell::set(self, len) }
+
+    fn get_mut(&mut self) -> &mut usize { Cell::get_mut(self) }
+}
+
+impl SharedLen for AtomicUsize {
+    fn get(&self) -> usize { self.load(Ordering::Acquire) }
+
+    fn set(&self, len: usize) { self.store(len, Ordering::Release) }
+
+    fn get_mut(&mut self) -> &mut usize { AtomicUsize::get_mut(self) }
+}
+
+struct Shared<T, L: SharedLen> {
+    len: L,
+    buffer: Heap<T>,
+}
+
+impl<T, L: SharedLen> Drop for Shared<T, L> {
+    fn drop(&mut self) {
+        // Safety: the first `len` items are initialized, and this is the last owner
+        let len = *self.len.get_mut();
+        unsafe { core::ptr::drop_in_place(crate::slice_assume_init_mut(&mut self.buffer[..len])) }
+    }
+}
+
+/// Clones the first `len` items of `buffer` into a new buffer,
+/// which is reserved with `reserve`
+fn clone_buffer<T: Clone>(
+    buffer: &[MaybeUninit<T>],
+    len: usize,
+    capacity: usize,
+    reserve: impl FnOnce(&mut Heap<T>, usize) -> AllocResult,
+) -> Result<Heap<T>, AllocError> {
+    let mut heap = Heap::<T>::default();
+    reserve(&mut heap, capacity.max(buffer.len()))?;
+    let mut vec = HeapVec::with_storage(heap);
+    // Safety
+    //
+    // * the first `len` items of a shared buffer are initialized
+    // * the new buffer has enough capacity for all of them
+    unsafe { vec.extend_from_slice_reserved(crate::slice_assume_init_ref(&buffer[..len])) };
+    Ok(vec.into_raw_parts().1)
+}
+
+/// Reserves space in `heap`, and aborts if that is not possible
+///
+/// This never panics, so that mutable access to a shared vector can't panic
+#[allow(clippy::unnecessary_wraps)]
+fn abort_reserve<T>(heap: &mut Heap<T>, capacity: usize) -> AllocResult {
+    if heap.try_reserve(capacity).is_err() {
+        let layout = Layout::array::<T>(capacity).unwrap_or_else(|_| Layout::new::<T>());
+        std::alloc::handle_alloc_error(layout)
+    }
+    Ok(())
+}
+
+macro_rules! shared_storage {
+    ($(#[$meta:meta])* $name:ident, $ptr:ident, $len:ty) => {
+        $(#[$meta])*
+        pub struct $name<T>($ptr<Shared<T, $len>>);
+
+        impl<T> $name<T> {
+            /// Create a new empty storage
+            pub fn new() -> Self { Self::from_heap(Box::default(), 0) }
+
+            /// Returns `true` if this storage is shared with another vector
+            pub fn is_shared(&self) -> bool { $ptr::strong_count(&self.0) > 1 }
+
+            pub(crate) fn from_heap(buffer: Heap<T>, len: usize) -> Self {
+                Self($ptr::new(Shared {
+                    len: <$len>::new(len),
+                    buffer,
+                }))
+            }
+
+            /// Clones the storage without copying the buffer, given the
+            /// number of initialized items in this storage.
+            ///
+            /// # Safety
+            ///
+            /// The first `len` items of the storage must be initialized
+            pub(crate) unsafe fn share(&self, len: usize) -> Self {
+                // If the buffer is already shared, then the length is already up to date
+                // and no one is allowed to modify the buffer
+                if !self.is_shared() {
+                    self.0.len.set(len);
+                }
+
+                Self($ptr::clone(&self.0))
+            }
+
+            /// Takes the buffer out of the storage, if it is not shared
+            #[cfg_attr(feature = "no-panic", allow(dead_code))]
+            pub(crate) fn try_into_heap(mut self) -> Result<Heap<T>, Self> {
+                match $ptr::get_mut(&mut self.0) {
+                    Some(shared) => {
+                        *shared.len.get_mut() = 0;
+                        Ok(core::mem::take(&mut shared.buffer))
+                    }
+                    None => Err(self),
+                }
+            }
+        }
+
+        impl<T: Clone> $name<T> {
+            /// Makes sure that this storage isn't shared, and has a capacity of at least `capacity`
+            fn make_unique<F>(&mut self, capacity: usize, reserve: F) -> AllocResult
+            where
+                F: FnOnce(&mut Heap<T>, usize) -> AllocResult,
+            {
+                let Some(shared) = $ptr::get_mut(&mut self.0) else {
+                    let buffer = clone_buffer(&self.0.buffer, self.0.len.get(), capacity, reserve)?;
+                    // if the other owners released the old buffer in the meantime,
+                    // then dropping it here will also drop its items
+                    *self = Self::from_heap(buffer, 0);
+                    return Ok(())
+                };
+
+                // The length is only tracked while the buffer is shared,
+                // and a unique owner may change it at any time
+                *shared.len.get_mut() = 0;
+
+                if shared.buffer.len() < capacity {
+                    reserve(&mut shared.buffer, capacity)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        impl<T> Default for $name<T> {
+            fn default() -> Self { Self::new() }
+        }
+
+        impl<T> AsRef<[MaybeUninit<T>]> for $name<T> {
+            fn as_ref(&self) -> &[MaybeUninit<T>] { &self.0.buffer }
+        }
+
+        impl<T: Clone> AsMut<[MaybeUninit<T>]> for $name<T> {
+            /// Clones the buffer if it is shared, and aborts if that is not possible
+            ///
+            /// Use [`GenericVec::try_reserve`](crate::GenericVec::try_reserve) first to
+            /// handle the allocation failure instead
+            fn as_mut(&mut self) -> &mut [MaybeUninit<T>] {
+                let _ = self.make_unique(0, abort_reserve);
+
+                match $ptr::get_mut(&mut self.0) {
+                    Some(shared) => &mut shared.buffer,
+                    // `make_unique` with `abort_reserve` always leaves the buffer unique
+                    None => &mut [],
+                }
+            }
+        }
+
+        unsafe impl<T: Clone> Storage for $name<T> {
+            type Item = T;
+
+            fn reserve(&mut self, new_capacity: usize) { let _ = self.make_unique(new_capacity, abort_reserve); }
+
+            fn try_reserve(&mut self, new_capacity: usize) -> AllocResult {
+                self.make_unique(new_capacity, Storage::try_reserve)
+            }
+
+            #[doc(hidden)]
+            fn __try_make_mut(&mut self) -> AllocResult { self.make_unique(0, Storage::try_reserve) }
+
+            /// A shared buffer is counted in full by every vector that shares it
+            fn heap_bytes(&self) -> usize {
+                core::mem::size_of::<Shared<T, $len>>() + core::mem::size_of_val::<[MaybeUninit<T>]>(&self.0.buffer)
+            }
+
+            #[doc(hidden)]
+            unsafe fn __drop_items(&mut self, range: Range<usize>) {
+                match $ptr::get_mut(&mut self.0) {
+                    Some(shared) => unsafe {
+                        *shared.len.get_mut() = 0;
+                        core::ptr::drop_in_place(crate::slice_assume_init_mut(&mut shared.buffer[range]));
+                    },
+                    // the items are still owned by the other vectors that share this buffer,
+                    // and will be dropped by the last one
+                    None => debug_assert_eq!(range.start, 0),
+                }
+            }
+        }
+    };
+}
+
+shared_storage! {
+    /// A reference counted storage that can be shared between threads
+    ///
+    /// Cloning an [`ArcVec`](crate::ArcVec) only clones the reference, and the items are
+    /// only cloned into a new buffer the first time that a shared vector is modified.
+    ArcStorage, Arc, AtomicUsize
+}
+
+shared_storage! {
+    /// A reference counted storage that can be shared within a thread
+    ///
+    /// Cloning an [`RcVec`](crate::RcVec) only clones the reference, and the items are
+    /// only cloned into a new buffer the first time that a shared vector is modified.
+    RcStorage, Rc, Cell<usize>
+}
@@ -0,0 +1,172 @@
+use crate::raw::{AllocError, AllocResult, FlattenStorage, Storage, StorageWithCapacity};
+
+use core::{
+    mem::{size_of, MaybeUninit},
+    ops::Range,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+/// A storage wrapper that clears every slot that stops holding an item
+///
+/// Slots are overwritten with zeroes using volatile writes, so that the writes
+/// are not optimized away. This happens when
+///
+/// * items are removed from the vector (for example by
+///   [`truncate`](crate::GenericVec::truncate), [`pop`](crate::GenericVec::pop)
+///   or [`drain`](crate::GenericVec::drain))
+/// * the storage is grown, in which case the items are copied to a new
+///   storage and the old storage is cleared before it is released
+/// * the storage is dropped, in which case the whole storage is cleared
+///
+/// ```rust
+/// # #[cfg(not(feature = "no-panic"))] {
+/// use cl_generic_vec::{raw::Zeroizing, GenericVec};
+/// use core::mem::MaybeUninit;
+///
+/// let mut key = GenericVec::<u8, Zeroizing<[MaybeUninit<u8>; 64]>>::new();
+/// key.extend_from_slice(b"hunter2");
+/// key.truncate(0);
+/// assert!(key.storage().as_ref()[..7].iter().all(|b| unsafe { b.assume_init() } == 0));
+/// # }
+/// ```
+///
+/// Note that moving a vector backed by an array storage copies the storage,
+/// and those copies are not cleared.
+#[repr(transparent)]
+pub struct Zeroizing<S: ?Sized + Storage>(S);
+
+impl<S: Storage> Zeroizing<S> {
+    /// Wrap a storage
+    pub fn new(storage: S) -> Self { Self(storage) }
+}
+
+impl<T, const N: usize> crate::GenericVec<T, Zeroizing<[MaybeUninit<T>; N]>> {
+    /// Create a new empty zeroizing `ArrayVec`
+    pub fn new() -> Self { Self::with_storage(Zeroizing::new(crate::uninit_array())) }
+}
+
+/// Overwrite the slots with zeroes, without being optimized away
+fn zeroize<T>(slots: &mut [MaybeUninit<T>]) {
+    if size_of::<T>() == 0 {
+        return
+    }
+
+    for slot in slots {
+        // Safety: the slot is valid to write
+        unsafe { core::ptr::write_volatile(slot, MaybeUninit::zeroed()) }
+    }
+
+    compiler_fence(Ordering::SeqCst);
+}
+
+impl<S: ?Sized + Storage> Drop for Zeroizing<S> {
+    fn drop(&mut self) { zeroize(self.0.as_mut()) }
+}
+
+impl<S: ?Sized + Storage> AsRef<[MaybeUninit<S::Item>]> for Zeroizing<S> {
+    fn as_ref(&self) -> &[MaybeUninit<S::Item>] { self.0.as_ref() }
+}
+
+impl<S: ?Sized + Storage> AsMut<[MaybeUninit<S::Item>]> for Zeroizing<S> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<S::Item>] { self.0.as_mut() }
+}
+
+impl<S: StorageWithCapacity> Zeroizing<S> {
+    /// Moves the items into a new storage, that was reserved with `reserve`,
+    /// and clears the old storage
+    fn grow<F>(&mut self, new_capacity: usize, reserve: F) -> AllocResult
+    where
+        F: FnOnce(&mut S, usize) -> AllocResult,
+    {
+        let capacity = self.0.as_ref().len();
+        if new_capacity <= capacity {
+            return Ok(())
+        }
+
+        let mut storage = S::with_capacity(0);
+        reserve(&mut storage, new_capacity.max(capacity.saturating_mul(2)))?;
+
+        // Safety: the new storage has a capacity of at least `capacity`, and
+        // the items are not used from the old storage after this
+        unsafe {
+            storage
+                .as_mut()
+                .as_mut_ptr()
+                .copy_from_nonoverlapping(self.0.as_ref().as_ptr(), capacity);
+        }
+
+        // dropping the old storage clears it
+        drop(core::mem::replace(self, Self(storage)));
+
+        Ok(())
+    }
+}
+
+unsafe impl<S: StorageWithCapacity> Storage for Zeroizing<S> {
+    type Item = S::Item;
+
+    #[doc(hidden)]
+    const CONST_CAPACITY: Option<usize> = S::CONST_CAPACITY;
+
+    fn reserve(&mut self, new_capacity: usize) {
+        let _ = self.grow(new_capacity, |storage, capacity| {
+            storage.reserve(capacity);
+            Ok(())
+        });
+    }
+
+    fn try_reserve(&mut self, new_capacity: usize) -> AllocResult { self.grow(new_capacity, S::try_reserve) }
+
+    fn heap_bytes(&self) -> usize { self.0.heap_bytes() }
+
+    #[doc(hidden)]
+    fn __release(&mut self, range: Range<usize>) {
+        self.0.__release(range.clone());
+        if size_of::<S::Item>() != 0 {
+            zeroize(&mut self.0.as_mut()[range]);
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __acquire(&mut self, range: Range<usize>) { self.0.__acquire(range) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __set_len(&mut self, len: usize) { self.0.__set_len(len) }
+
+    #[doc(hidden)]
+    #[inline]
+    fn __try_make_mut(&mut self) -> AllocResult { self.0.__try_make_mut() }
+}
+
+unsafe impl<S: StorageWithCapacity> StorageWithCapacity for Zeroizing<S> {
+    fn with_capacity(capacity: usize) -> Self { Self::new(S::with_capacity(capacity)) }
+
+    fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> { S::try_with_capacity(capacity).map(Self::new) }
+
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    fn __with_capacity__const_capacity_checked(capacity: usize, old_capacity: Option<usize>) -> Self {
+        Self::new(S::__with_capacity__const_capacity_checked(capacity, old_capacity))
+    }
+}
+
+impl<S: StorageWithCapacity + Default> Default for Zeroizing<S> {
+    fn default() -> Self { Self::new(S::default()) }
+}
+
+unsafe impl<T, S, const N: usize> FlattenStorage<T, N> for Zeroizing<S>
+where
+    S: StorageWithCapacity + FlattenStorage<T, N>,
+    S::Flattened: StorageWithCapacity,
+{
+    type Flattened = Zeroizing<S::Flattened>;
+
+    fn flatten(self) -> Self::Flattened {
+        let this = core::mem::ManuallyDrop::new(self);
+        // Safety: `this` is never used or dropped again
+        let storage = unsafe { core::ptr::read(core::ptr::addr_of!(this.0)) };
+        Zeroizing(storage.flatten())
+    }
+}
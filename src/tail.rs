@@ -0,0 +1,77 @@
+use crate::{SimpleVec, SliceVec, Storage};
+
+use core::{
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+/// This struct is created by [`GenericVec::tail_vec`](crate::GenericVec::tail_vec).
+/// See its documentation for more.
+pub struct TailVec<'a, S: ?Sized + Storage> {
+    len: &'a mut usize,
+    storage: NonNull<S>,
+    start: *const S::Item,
+    capacity: usize,
+    tail: ManuallyDrop<SliceVec<'a, S::Item>>,
+    mark: PhantomData<&'a mut S>,
+}
+
+unsafe impl<S: ?Sized + Storage + Send> Send for TailVec<'_, S> where S::Item: Send {}
+unsafe impl<S: ?Sized + Storage + Sync> Sync for TailVec<'_, S> where S::Item: Sync {}
+
+impl<'a, S: ?Sized + Storage> TailVec<'a, S> {
+    pub(crate) fn new(vec: &'a mut SimpleVec<S>) -> Self {
+        let SimpleVec { len, storage } = vec;
+        let capacity = storage.as_ref().len();
+        storage.__acquire(*len..capacity);
+        let mut storage = NonNull::from(storage);
+        // Safety
+        //
+        // * the spare capacity is uninitialized
+        // * the storage is only used again once the tail is done
+        let tail = unsafe { SliceVec::new(&mut storage.as_mut().as_mut()[*len..]) };
+
+        Self {
+            len,
+            storage,
+            start: tail.as_ptr(),
+            capacity: tail.storage().len(),
+            tail: ManuallyDrop::new(tail),
+            mark: PhantomData,
+        }
+    }
+}
+
+impl<'a, S: ?Sized + Storage> Deref for TailVec<'a, S> {
+    type Target = SliceVec<'a, S::Item>;
+
+    fn deref(&self) -> &Self::Target { &self.tail }
+}
+
+impl<S: ?Sized + Storage> DerefMut for TailVec<'_, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.tail }
+}
+
+impl<S: ?Sized + Storage> Drop for TailVec<'_, S> {
+    fn drop(&mut self) {
+        let len = *self.len;
+        let end = len + self.capacity;
+
+        if self.tail.as_ptr() == self.start && self.tail.storage().len() == self.capacity {
+            // the elements of the tail are right after the initialized
+            // part of the parent vector, so they just need to be claimed
+            *self.len += self.tail.len();
+            // Safety: the tail isn't used anymore
+            let storage = unsafe { self.storage.as_mut() };
+            storage.__release(*self.len..end);
+            storage.__set_len(*self.len);
+        } else {
+            // the tail was replaced, so it isn't backed by the parent vector.
+            // The original tail may still be alive and own items in the spare
+            // capacity, so its slots are left untouched
+            unsafe { ManuallyDrop::drop(&mut self.tail) }
+        }
+    }
+}
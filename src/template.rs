@@ -0,0 +1,123 @@
+//! The test templates that are run on every storage in this crate
+//!
+//! Each template is a macro that expands to a set of tests, and `__template_tests!`
+//! expands all of them, once with `Copy` items and once with owned items. Where they
+//! are expanded, the templates need
+//!
+//! * a `new_vec!($vec:pat, max($len:expr))` macro that creates the vector under test
+//! * an `S!` macro that turns integers, strings or arrays of them into owned items
+//! * a `template_test` attribute, which marks the tests
+//!
+//! The templates are exported so that [`conformance_tests!`](crate::conformance_tests)
+//! can run them on storages from other crates. Since a `#[cfg]` in them would check
+//! the features of the crate that expands them, they use the `__template_if_*` macros
+//! to check the features of this crate instead.
+
+mod copy {
+    mod cursor;
+    mod drain;
+    mod into_iter;
+    mod simple;
+    mod splice;
+    mod vec_ops;
+}
+
+mod owned {
+    mod cursor;
+    mod drain;
+    mod into_iter;
+    mod simple;
+    mod splice;
+    mod vec_ops;
+}
+
+/// Expands to the given items if the `alloc` feature is enabled
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_if_alloc {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Expands to the given items if the `alloc` feature is enabled
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_if_alloc {
+    ($($item:item)*) => {};
+}
+
+/// Expands to the given items if the `std` feature is enabled
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_if_std {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Expands to the given items if the `std` feature is enabled
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_if_std {
+    ($($item:item)*) => {};
+}
+
+/// Expands to the given items if the `no-panic` feature is disabled
+#[cfg(not(feature = "no-panic"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_if_panicking {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Expands to the given items if the `no-panic` feature is disabled
+#[cfg(feature = "no-panic")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_if_panicking {
+    ($($item:item)*) => {};
+}
+
+/// Expands every template into its own module, the given items are put
+/// at the start of each of those modules
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_tests {
+    (@files $prelude:tt $($file:ident => $template:ident,)*) => {
+        $(
+            mod $file {
+                $crate::__template_tests!(@prelude $prelude);
+                $crate::$template!();
+            }
+        )*
+    };
+    (@prelude [$($prelude:item)*]) => { $($prelude)* };
+    ($($prelude:item)*) => {
+        mod copy {
+            $crate::__template_tests! {
+                @files [$($prelude)*]
+                cursor => __template_copy_cursor,
+                drain => __template_copy_drain,
+                into_iter => __template_copy_into_iter,
+                simple => __template_copy_simple,
+                splice => __template_copy_splice,
+                vec_ops => __template_copy_vec_ops,
+            }
+        }
+
+        $crate::__template_if_alloc! {
+            mod owned {
+                $crate::__template_tests! {
+                    @files [$($prelude)*]
+                    cursor => __template_owned_cursor,
+                    drain => __template_owned_drain,
+                    into_iter => __template_owned_into_iter,
+                    simple => __template_owned_simple,
+                    splice => __template_owned_splice,
+                    vec_ops => __template_owned_vec_ops,
+                }
+            }
+        }
+    };
+}
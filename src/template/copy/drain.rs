@@ -0,0 +1,160 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_copy_drain {
+    () => {
+        #[template_test]
+        fn drain() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+            assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+            vec.drain(4..7);
+
+            assert_eq!(vec, [0, 1, 2, 3, 7]);
+
+            assert!(vec.drain(1..3).rev().eq([2, 1].iter().copied()));
+
+            assert_eq!(vec, [0, 3, 7]);
+        }
+
+        #[template_test]
+        fn drain_filter() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+            vec.drain_filter(.., |&mut x| x % 2 == 0);
+
+            assert_eq!(vec, [1, 3, 5, 7]);
+
+            assert!(vec.drain_filter(.., |&mut x| x % 3 == 0).eq([3].iter().copied()));
+
+            assert_eq!(vec, [1, 5, 7]);
+        }
+
+        #[template_test]
+        fn try_drain_filter() {
+            use core::ops::ControlFlow;
+
+            new_vec!(mut vec, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+            let mut filter = vec.try_drain_filter(.., |&mut x| {
+                if x == 5 {
+                    ControlFlow::Break(x)
+                } else {
+                    ControlFlow::Continue(x % 2 == 0)
+                }
+            });
+            assert!(filter.by_ref().eq([Ok(0), Ok(2), Ok(4), Err(5)].iter().copied()));
+            drop(filter);
+
+            assert_eq!(vec, [1, 3, 5, 6, 7]);
+
+            assert!(vec
+                .try_drain_filter(.., |&mut x| if x == 3 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(x > 5)
+                })
+                .rev()
+                .eq([Ok(7), Ok(6), Err(())].iter().copied()));
+
+            assert_eq!(vec, [1, 3, 5]);
+        }
+
+        #[template_test]
+        fn drain_filter_indexed() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend([7, 6, 5, 4, 3, 2, 1, 0].iter().copied());
+
+            assert!(vec
+                .drain_filter_indexed(2.., |i, _| i % 3 == 0)
+                .eq([4, 1].iter().copied()));
+            assert_eq!(vec, [7, 6, 5, 3, 2, 0]);
+
+            assert!(vec
+                .drain_filter_indexed(.., |i, _| i < 2)
+                .rev()
+                .eq([6, 7].iter().copied()));
+            assert_eq!(vec, [5, 3, 2, 0]);
+
+            vec.retain_indexed(|i, &mut x| i != x);
+            assert_eq!(vec, [5, 3, 0]);
+        }
+
+        #[template_test]
+        fn try_retain() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+            assert_eq!(
+                vec.try_retain(|&mut x| if x == 4 { Err(x) } else { Ok(x % 2 == 1) }),
+                Err(4)
+            );
+            assert_eq!(vec, [1, 3, 4, 5, 6, 7]);
+
+            assert_eq!(vec.try_retain(|&mut x| Ok::<_, ()>(x > 3)), Ok(()));
+            assert_eq!(vec, [4, 5, 6, 7]);
+        }
+
+        #[template_test]
+        fn retain_into() {
+            new_vec!(mut vec, max(8));
+            new_vec!(mut other, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+            other.push(9);
+
+            vec.retain_into(&mut other, |&mut x| x == 0 || x == 3 || x == 7);
+
+            assert_eq!(vec, [0, 3, 7]);
+            assert_eq!(other, [9, 1, 2, 4, 5, 6]);
+        }
+
+        #[template_test]
+        fn drain_keep_rest() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+            let mut drain = vec.drain(2..6);
+            assert_eq!(drain.as_slice(), [2, 3, 4, 5]);
+            assert_eq!(drain.next(), Some(2));
+            drain.as_mut_slice()[0] = 9;
+            assert_eq!(drain.next_back(), Some(5));
+            drain.keep_rest();
+
+            assert_eq!(vec, [0, 1, 9, 4, 6, 7]);
+        }
+
+        $crate::__template_if_panicking! {
+            #[template_test]
+            fn drain_into_vec() {
+                new_vec!(mut vec, max(8));
+
+                vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                let mut drain = vec.drain(2..6);
+                assert_eq!(drain.next(), Some(2));
+                let rest = drain.into_vec::<[_; 4]>();
+
+                assert_eq!(rest, [3, 4, 5]);
+                assert_eq!(vec, [0, 1, 6, 7]);
+
+                let drain = vec.drain(1..);
+                let drain = match drain.try_into_vec::<[_; 2]>() {
+                    Ok(_) => panic!("the items don't fit in the new vector"),
+                    Err(drain) => drain,
+                };
+                assert!(drain.try_into_vec::<[_; 3]>().ok().unwrap().eq(&[1, 6, 7]));
+                assert_eq!(vec, [0]);
+            }
+        }
+    };
+}
@@ -0,0 +1,13 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_copy_into_iter {
+    () => {
+        #[template_test]
+        pub fn into_iter() {
+            new_vec!(mut vec, max(8));
+            vec.extend(0..8);
+
+            assert!((0..8).eq(vec));
+        }
+    };
+}
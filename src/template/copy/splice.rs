@@ -0,0 +1,86 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_copy_splice {
+    () => {
+        $crate::__template_if_panicking! {
+            #[template_test]
+            fn splice_exact_or_more() {
+                new_vec!(mut vec, max(9));
+
+                vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                vec.splice(2..5, [4, 3, 2, 1].iter().copied());
+                assert_eq!(vec, [0, 1, 4, 3, 2, 1, 5, 6, 7]);
+            }
+
+            #[template_test]
+            fn splice_less() {
+                new_vec!(mut vec, max(10));
+
+                vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                vec.splice(2..5, [9, 8].iter().copied());
+
+                assert_eq!(vec, [0, 1, 9, 8, 5, 6, 7]);
+            }
+
+            #[template_test]
+            fn splice_from_zero() {
+                new_vec!(mut vec, max(10));
+
+                vec.splice(0..0, [0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+            }
+
+            #[template_test]
+            fn splice_unknown_len() {
+                new_vec!(mut vec, max(10));
+
+                vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                vec.splice(2..4, (10..15).filter(|x| x % 2 == 0));
+                assert_eq!(vec, [0, 1, 10, 12, 14, 4, 5, 6, 7]);
+
+                vec.splice(1..2, (20..40).take_while(|&x| x < 22));
+                assert_eq!(vec, [0, 20, 21, 10, 12, 14, 4, 5, 6, 7]);
+            }
+
+            #[template_test]
+            fn splice_wrong_size_hint() {
+                struct Lying<I>(I);
+
+                impl<I: Iterator> Iterator for Lying<I> {
+                    type Item = I::Item;
+
+                    fn next(&mut self) -> Option<I::Item> { self.0.next() }
+
+                    fn size_hint(&self) -> (usize, Option<usize>) { (2, Some(2)) }
+                }
+
+                new_vec!(mut vec, max(10));
+
+                vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                vec.splice(2..3, Lying([9, 9].iter().copied()));
+                assert_eq!(vec, [0, 1, 9, 9, 3, 4, 5, 6, 7]);
+            }
+
+            #[template_test]
+            fn splice_from_slice() {
+                new_vec!(mut vec, max(10));
+
+                vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().copied());
+
+                vec.splice_from_slice(1..3, &[9, 9, 9, 9]);
+                assert_eq!(vec, [0, 9, 9, 9, 9, 3, 4, 5, 6, 7]);
+
+                vec.splice_from_slice(..5, &[]);
+                assert_eq!(vec, [3, 4, 5, 6, 7]);
+
+                vec.splice_from_slice(5.., &[1, 2]);
+                assert_eq!(vec, [3, 4, 5, 6, 7, 1, 2]);
+            }
+        }
+    };
+}
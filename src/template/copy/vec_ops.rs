@@ -0,0 +1,53 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_copy_vec_ops {
+    () => {
+        use $crate::SliceVec;
+
+        #[template_test]
+        fn split_off() {
+            new_vec!(mut vec, max(8));
+            vec.extend(0..8);
+            let mut other = $crate::uninit_array::<_, 4>();
+            let mut other = unsafe { SliceVec::new(&mut other) };
+            vec.split_off_into(4, &mut other);
+            assert_eq!(vec, [0, 1, 2, 3]);
+            assert_eq!(other, [4, 5, 6, 7]);
+        }
+
+        #[template_test]
+        fn grow() {
+            new_vec!(mut vec, max(4));
+            vec.grow(4, 0);
+            assert_eq!(vec, [0; 4]);
+        }
+
+        $crate::__template_if_panicking! {
+            #[template_test]
+            fn split_spare() {
+                new_vec!(mut vec, max(8));
+                vec.push(0);
+                vec.split_spare([3, 4], |[a, b]| {
+                    b.extend([3, 4]);
+                    a.push(1);
+                    a.push(2);
+                });
+                assert_eq!(vec, [0, 1, 2, 3, 4]);
+            }
+        }
+
+        #[template_test]
+        fn tail_vec() {
+            new_vec!(mut vec, max(8));
+            vec.reserve(8);
+            vec.push(0);
+            {
+                let mut tail = vec.tail_vec();
+                tail.push(1);
+                tail.extend([2, 3]);
+                assert_eq!(*tail, [1, 2, 3]);
+            }
+            assert_eq!(vec, [0, 1, 2, 3]);
+        }
+    };
+}
@@ -0,0 +1,107 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_owned_drain {
+    () => {
+        #[template_test]
+        fn drain() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend([0, 1, 2, 3, 4, 5, 6, 7].iter().map(|x| S!(x)));
+
+            assert_eq!(vec, S!([0, 1, 2, 3, 4, 5, 6, 7]));
+
+            vec.drain(4..7);
+
+            assert_eq!(vec, S!([0, 1, 2, 3, 7]));
+
+            assert!(vec.drain(1..3).rev().eq([2, 1].iter().map(|x| S!(x))));
+
+            assert_eq!(vec, S!([0, 3, 7]));
+        }
+
+        #[template_test]
+        fn drain_filter() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend(
+                ["0", "00", "000", "0000", "00000", "000000", "0000000", "00000000"]
+                    .iter()
+                    .map(|x| S!(x)),
+            );
+
+            vec.drain_filter(.., |x| x.len() % 2 == 0);
+
+            assert_eq!(vec, S!(["0", "000", "00000", "0000000",]));
+
+            assert!(vec.drain_filter(.., |x| x.len() % 3 == 0).eq(Some(S!("000"))));
+
+            assert_eq!(vec, S!(["0", "00000", "0000000",]));
+        }
+
+        #[template_test]
+        fn try_drain_filter() {
+            use core::ops::ControlFlow;
+
+            new_vec!(mut vec, max(8));
+
+            vec.extend(["0", "00", "000", "0000", "00000"].iter().map(|x| S!(x)));
+
+            let mut filter = vec.try_drain_filter(.., |x| {
+                if x.len() == 4 {
+                    ControlFlow::Break(x.len())
+                } else {
+                    ControlFlow::Continue(x.len() % 2 == 0)
+                }
+            });
+            assert_eq!(filter.next(), Some(Ok(S!("00"))));
+            assert_eq!(filter.next(), Some(Err(4)));
+            assert_eq!(filter.next(), None);
+            drop(filter);
+
+            assert_eq!(vec, S!(["0", "000", "0000", "00000"]));
+        }
+
+        #[template_test]
+        fn try_retain() {
+            new_vec!(mut vec, max(8));
+
+            vec.extend(["0", "00", "000", "0000", "00000"].iter().map(|x| S!(x)));
+
+            assert_eq!(
+                vec.try_retain(|x| if x.len() == 3 { Err(()) } else { Ok(x.len() != 2) }),
+                Err(())
+            );
+            assert_eq!(vec, S!(["0", "000", "0000", "00000"]));
+
+            vec.retain_indexed(|i, _| i % 2 == 0);
+            assert_eq!(vec, S!(["0", "0000"]));
+        }
+
+        #[template_test]
+        fn retain_into() {
+            new_vec!(mut vec, max(8));
+            new_vec!(mut other, max(8));
+
+            vec.extend(["0", "00", "000", "0000", "00000"].iter().map(|x| S!(x)));
+
+            vec.retain_into(&mut other, |x| x.len() % 2 == 1);
+
+            assert_eq!(vec, S!(["0", "000", "00000"]));
+            assert_eq!(other, S!(["00", "0000"]));
+        }
+
+        $crate::__template_if_panicking! {
+            #[template_test]
+            fn drain_keep_rest() {
+                new_vec!(mut vec, max(8));
+
+                vec.extend(["0", "00", "000", "0000", "00000"].iter().map(|x| S!(x)));
+
+                let mut drain = vec.drain(1..);
+                assert_eq!(drain.next(), Some(S!("00")));
+                let rest = drain.into_vec::<[_; 4]>();
+                assert_eq!(rest, S!(["000", "0000", "00000"]));
+            }
+        }
+    };
+}
@@ -0,0 +1,83 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __template_owned_vec_ops {
+    () => {
+        use $crate::SliceVec;
+
+        #[template_test]
+        fn split_off() {
+            new_vec!(mut vec, max(8));
+            vec.extend((0..8).map(|x| S!(x)));
+            let mut other = $crate::uninit_array::<_, 4>();
+            let mut other = unsafe { SliceVec::new(&mut other) };
+            vec.split_off_into(4, &mut other);
+            assert_eq!(vec, S!([0, 1, 2, 3]));
+            assert_eq!(other, S!([4, 5, 6, 7]));
+        }
+
+        #[template_test]
+        fn consume_extend() {
+            new_vec!(mut vec, max(4));
+            let mut other = $crate::uninit_array::<_, 4>();
+            let mut other = unsafe { SliceVec::new(&mut other) };
+            other.extend((0..4).map(|x| S!(x)));
+            other.split_off_into(0, &mut vec);
+            assert_eq!(vec, S!([0, 1, 2, 3]));
+            assert_eq!(other, []);
+        }
+
+        #[template_test]
+        fn grow() {
+            new_vec!(mut vec, max(4));
+            vec.grow(4, S!(0));
+            assert_eq!(vec, [S!(0), S!(0), S!(0), S!(0)]);
+        }
+
+        $crate::__template_if_panicking! {
+            #[template_test]
+            fn split_spare() {
+                new_vec!(mut vec, max(8));
+                vec.push(S!(0));
+                vec.split_spare([2, 2, 3], |[a, b, c]| {
+                    c.extend((3..6).map(|x| S!(x)));
+                    a.push(S!(1));
+                    b.push(S!(2));
+                    let replaced = core::mem::replace(b, unsafe { SliceVec::new(&mut []) });
+                    assert_eq!(replaced, S!([2]));
+                });
+                assert_eq!(vec, S!([0, 1, 3, 4, 5]));
+            }
+        }
+
+        #[template_test]
+        fn tail_vec() {
+            new_vec!(mut vec, max(8));
+            vec.reserve(8);
+            vec.push(S!(0));
+            vec.tail_vec().push(S!(1));
+            {
+                let mut tail = vec.tail_vec();
+                tail.push(S!(2));
+                let replaced = core::mem::replace(&mut *tail, unsafe { SliceVec::new(&mut []) });
+                assert_eq!(replaced, S!([2]));
+            }
+            assert_eq!(vec, S!([0, 1]));
+        }
+
+        $crate::__template_if_std! {
+            #[test]
+            fn tail_vec_panic() {
+                new_vec!(mut vec, max(8));
+                vec.reserve(8);
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    let mut tail = vec.tail_vec();
+                    tail.push(S!(0));
+                    tail.push(S!(1));
+                    panic!("oops");
+                }));
+                assert!(result.is_err());
+                assert_eq!(vec, S!([0, 1]));
+            }
+        }
+    };
+}
@@ -0,0 +1,390 @@
+//! A conformance test suite for [`Storage`] implementations
+//!
+//! [`conformance_tests!`](crate::conformance_tests) runs the test templates that this crate
+//! runs on its own storages, once with `Copy` items and once with owned [`Item`]s, on a vector
+//! with another storage. It also runs [`clone_panic`] and [`drop_panic`], which use [`Tracked`]
+//! items to check that no items are leaked or dropped twice when `Clone` or `Drop` panic.
+//! Every test checks with a [`LeakCheck`] that all of the items it created were dropped.
+//!
+//! ```rust
+//! mod heap_vec {
+//!     cl_generic_vec::conformance_tests!(Box::<[_]>::default());
+//! }
+//! ```
+//!
+//! Storages that can't be created in a single expression, like borrowed storages, can
+//! pass a macro that creates the vector instead. It gets the pattern that the vector is
+//! bound to, and the capacity that the test needs.
+//!
+//! ```rust
+//! mod slice_vec {
+//!     macro_rules! slice_vec {
+//!         ($vec:pat, max($len:expr)) => {
+//!             let mut buffer = cl_generic_vec::uninit_array::<_, $len>();
+//!             let $vec = unsafe { cl_generic_vec::SliceVec::new(&mut buffer) };
+//!         };
+//!     }
+//!
+//!     cl_generic_vec::conformance_tests!(macro slice_vec);
+//! }
+//! ```
+//!
+//! [`check_ops`] and [`check_random`] apply a sequence of [`Op`]s to a vector
+//! and to a [`Vec`], and assert that both behave the same.
+
+// every check panics when it fails
+#![allow(clippy::missing_panics_doc)]
+
+mod model;
+
+pub use model::{check_ops, check_random, CursorOp, Op, OpGenerator};
+
+use crate::{raw::Storage, GenericVec};
+
+use core::{fmt, ops::Deref};
+use std::{
+    cell::{Cell, RefCell},
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The minimum capacity that a storage needs to run the checks
+pub const MIN_CAPACITY: usize = 16;
+
+std::thread_local! {
+    static LIVE_ITEMS: Cell<isize> = const { Cell::new(0) };
+    static LEAK_CHECKS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn live_items() -> isize { LIVE_ITEMS.with(Cell::get) }
+
+/// An owned string that keeps count of how many items are alive on the current thread
+///
+/// The test templates use it for their owned items, and a [`LeakCheck`] checks the count.
+#[derive(PartialEq, Eq)]
+pub struct Item(String);
+
+impl Item {
+    /// Create a new item
+    pub fn new(value: impl fmt::Display) -> Self {
+        LIVE_ITEMS.with(|live| live.set(live.get() + 1));
+        Self(value.to_string())
+    }
+}
+
+impl Clone for Item {
+    fn clone(&self) -> Self { Self::new(&self.0) }
+}
+
+impl Drop for Item {
+    fn drop(&mut self) { LIVE_ITEMS.with(|live| live.set(live.get() - 1)); }
+}
+
+impl Deref for Item {
+    type Target = str;
+
+    fn deref(&self) -> &str { &self.0 }
+}
+
+impl PartialEq<str> for Item {
+    fn eq(&self, other: &str) -> bool { self.0 == other }
+}
+
+impl PartialEq<&str> for Item {
+    fn eq(&self, other: &&str) -> bool { self.0 == *other }
+}
+
+impl fmt::Debug for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.0.fmt(f) }
+}
+
+/// Converts integers, strings and arrays of them into [`Item`]s
+pub trait IntoItem {
+    /// The converted value
+    type Item;
+
+    /// Converts the value
+    fn into_item(self) -> Self::Item;
+}
+
+impl IntoItem for i32 {
+    type Item = Item;
+
+    fn into_item(self) -> Item { Item::new(self) }
+}
+
+impl IntoItem for &i32 {
+    type Item = Item;
+
+    fn into_item(self) -> Item { Item::new(self) }
+}
+
+impl IntoItem for &str {
+    type Item = Item;
+
+    fn into_item(self) -> Item { Item::new(self) }
+}
+
+impl IntoItem for &&str {
+    type Item = Item;
+
+    fn into_item(self) -> Item { Item::new(self) }
+}
+
+impl<T: IntoItem, const N: usize> IntoItem for [T; N] {
+    type Item = [T::Item; N];
+
+    fn into_item(self) -> Self::Item { self.map(T::into_item) }
+}
+
+/// Checks that every [`Item`] that was created on the current thread while the
+/// check was alive was also dropped exactly once
+///
+/// When checks are nested, only the outermost one checks the count, so a test
+/// can create more than one vector.
+///
+/// # Panics
+///
+/// Panics when dropped if the number of live items changed, unless the thread
+/// is already panicking
+pub struct LeakCheck(Option<isize>);
+
+impl LeakCheck {
+    /// Start checking
+    pub fn start() -> Self {
+        let outer = LEAK_CHECKS.with(|checks| checks.replace(checks.get() + 1)) == 0;
+        Self(outer.then(live_items))
+    }
+}
+
+impl Drop for LeakCheck {
+    fn drop(&mut self) {
+        LEAK_CHECKS.with(|checks| checks.set(checks.get() - 1));
+
+        if let Some(live) = self.0 {
+            if !std::thread::panicking() {
+                assert_eq!(live_items(), live, "leaked or dropped the wrong number of items");
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    drops: RefCell<Vec<usize>>,
+    clones_left: Cell<Option<usize>>,
+    panic_on_drop: Cell<Option<i32>>,
+}
+
+/// Creates [`Tracked`] items, and keeps track of how many times each of them was dropped
+#[derive(Default)]
+pub struct Tracker(Rc<State>);
+
+impl Tracker {
+    /// Create a new tracker
+    pub fn new() -> Self { Self::default() }
+
+    /// Create a new item
+    pub fn item(&self, value: i32) -> Tracked {
+        let mut drops = self.0.drops.borrow_mut();
+        drops.push(0);
+        Tracked {
+            value,
+            id: drops.len() - 1,
+            state: self.0.clone(),
+        }
+    }
+
+    /// Make the clone after the next `clones` clones panic
+    pub fn panic_on_clone(&self, clones: usize) { self.0.clones_left.set(Some(clones)); }
+
+    /// Make the next drop of an item with the given value panic
+    pub fn panic_on_drop(&self, value: i32) { self.0.panic_on_drop.set(Some(value)); }
+
+    /// The number of items that were dropped at least once
+    pub fn dropped(&self) -> usize { self.0.drops.borrow().iter().filter(|&&drops| drops > 0).count() }
+
+    /// The number of items that were created but not yet dropped
+    pub fn live(&self) -> usize { self.0.drops.borrow().iter().filter(|&&drops| drops == 0).count() }
+
+    /// Asserts that no item was dropped twice, and that exactly `live` items are still alive
+    ///
+    /// # Panics
+    ///
+    /// Panics if an item was dropped more than once, or if the number of live items is different
+    pub fn assert_live(&self, live: usize) {
+        let drops = self.0.drops.borrow();
+        if let Some(id) = drops.iter().position(|&drops| drops > 1) {
+            panic!("item #{id} was dropped {} times", drops[id]);
+        }
+        drop(drops);
+        assert_eq!(self.live(), live, "leaked or dropped the wrong number of items");
+    }
+}
+
+/// An item created by a [`Tracker`]
+pub struct Tracked {
+    value: i32,
+    id: usize,
+    state: Rc<State>,
+}
+
+impl Tracked {
+    /// The value of this item
+    pub fn value(&self) -> i32 { self.value }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        match self.state.clones_left.get() {
+            Some(0) => {
+                self.state.clones_left.set(None);
+                panic!("cloning {self:?} panicked");
+            }
+            Some(n) => self.state.clones_left.set(Some(n - 1)),
+            None => (),
+        }
+
+        Tracker(self.state.clone()).item(self.value)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.state.drops.borrow_mut()[self.id] += 1;
+        if self.state.panic_on_drop.get() == Some(self.value) {
+            self.state.panic_on_drop.set(None);
+            panic!("dropping {self:?} panicked");
+        }
+    }
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+
+impl fmt::Debug for Tracked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "Tracked({})", self.value) }
+}
+
+type Vector<S> = GenericVec<Tracked, S>;
+
+fn values<S: ?Sized + Storage<Item = Tracked>>(vec: &Vector<S>) -> Vec<i32> {
+    vec.iter().map(Tracked::value).collect()
+}
+
+fn start<S: ?Sized + Storage<Item = Tracked>>(vec: &mut Vector<S>) -> Tracker {
+    assert!(vec.is_empty(), "the checks must be run on an empty vector");
+    assert!(
+        vec.try_reserve(MIN_CAPACITY).is_ok(),
+        "the checks need a storage that can hold at least {MIN_CAPACITY} items"
+    );
+    Tracker::new()
+}
+
+fn fill<S: ?Sized + Storage<Item = Tracked>>(vec: &mut Vector<S>, tracker: &Tracker, values: impl IntoIterator<Item = i32>) {
+    for value in values {
+        vec.push(tracker.item(value));
+    }
+}
+
+fn finish<S: ?Sized + Storage<Item = Tracked>>(vec: &mut Vector<S>, tracker: &Tracker) {
+    tracker.assert_live(vec.len());
+    vec.clear();
+    tracker.assert_live(0);
+}
+
+/// Checks that no items are leaked or dropped twice when `Clone` panics
+pub fn clone_panic<S: ?Sized + Storage<Item = Tracked>>(vec: &mut Vector<S>) {
+    let tracker = start(vec);
+
+    fill(vec, &tracker, 0..2);
+    let slice = [tracker.item(2), tracker.item(3), tracker.item(4), tracker.item(5)];
+    tracker.panic_on_clone(2);
+    let result = catch_unwind(AssertUnwindSafe(|| vec.extend_from_slice(&slice)));
+    assert!(result.is_err());
+    tracker.assert_live(vec.len() + slice.len());
+    drop(slice);
+
+    finish(vec, &tracker);
+}
+
+/// Checks that no items are leaked or dropped twice when `Drop` panics
+pub fn drop_panic<S: ?Sized + Storage<Item = Tracked>>(vec: &mut Vector<S>) {
+    let tracker = start(vec);
+
+    fill(vec, &tracker, 0..8);
+    tracker.panic_on_drop(5);
+    let result = catch_unwind(AssertUnwindSafe(|| vec.truncate(2)));
+    assert!(result.is_err());
+    assert_eq!(values(vec), [0, 1]);
+    tracker.assert_live(2);
+
+    fill(vec, &tracker, 2..8);
+    tracker.panic_on_drop(3);
+    let result = catch_unwind(AssertUnwindSafe(|| drop(vec.drain(1..6))));
+    assert!(result.is_err());
+    tracker.assert_live(vec.len());
+
+    finish(vec, &tracker);
+}
+
+/// Runs the test templates and the checks in [`testing`](crate::testing) on a [`GenericVec`]
+///
+/// The storage is either created by the given expression, and has to hold at least
+/// [`MIN_CAPACITY`](crate::testing::MIN_CAPACITY) items, or by the macro after `macro`,
+/// see [`testing`](crate::testing) for more.
+///
+/// ```rust
+/// mod heap_vec {
+///     cl_generic_vec::conformance_tests!(Box::<[_]>::default());
+/// }
+/// ```
+#[macro_export]
+macro_rules! conformance_tests {
+    (@tests) => {
+        $crate::__template_tests! {
+            #[allow(unused_imports)]
+            use ::core::prelude::v1::test as template_test;
+
+            #[allow(unused_macros)]
+            macro_rules! S {
+                ($value:expr) => { $crate::testing::IntoItem::into_item($value) };
+            }
+        }
+
+        #[test]
+        fn clone_panic() {
+            new_vec!(mut vec, max(16));
+            $crate::testing::clone_panic(&mut vec);
+        }
+
+        #[test]
+        fn drop_panic() {
+            new_vec!(mut vec, max(16));
+            $crate::testing::drop_panic(&mut vec);
+        }
+    };
+    (macro $new_vec:ident) => {
+        macro_rules! new_vec {
+            ($vec:pat, max($len:expr)) => {
+                let _leaks = $crate::testing::LeakCheck::start();
+                $new_vec!($vec, max($len));
+            };
+        }
+
+        $crate::conformance_tests!(@tests);
+    };
+    ($storage:expr) => {
+        macro_rules! new_vec {
+            ($vec:pat, max($len:expr)) => {
+                let _leaks = $crate::testing::LeakCheck::start();
+                let $vec = $crate::GenericVec::with_storage($storage);
+            };
+        }
+
+        $crate::conformance_tests!(@tests);
+    };
+}
@@ -0,0 +1,465 @@
+use super::{Tracked, Tracker};
+use crate::{raw::Storage, GenericVec, HeapVec};
+
+use core::{fmt::Write, ops::Range};
+use std::{
+    collections::VecDeque,
+    panic::{catch_unwind, AssertUnwindSafe},
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+/// An operation that [`check_ops`] applies to both a [`GenericVec`] and a [`Vec`]
+///
+/// Items are identified by their values. The operations that take a divisor
+/// (`m`) select items by their value modulo `m`, and `m` must not be zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    /// `push(value)`
+    Push(i32),
+    /// `pop()`, or `try_pop()` for [`GenericVec`]
+    Pop,
+    /// `insert(index, value)`
+    Insert(usize, i32),
+    /// `remove(index)`
+    Remove(usize),
+    /// `swap_remove(index)`
+    SwapRemove(usize),
+    /// `truncate(len)`
+    Truncate(usize),
+    /// `clear()`
+    Clear,
+    /// `extend_from_slice(values)`
+    ExtendFromSlice(Vec<i32>),
+    /// `resize(len, value)`
+    Resize(usize, i32),
+    /// `drain(range)`, collecting the removed items
+    Drain(Range<usize>),
+    /// removes the items in `range` that are divisible by `m`, collecting the removed items
+    DrainFilter(Range<usize>, i32),
+    /// `splice(range, values)`, collecting the removed items
+    Splice(Range<usize>, Vec<i32>),
+    /// keeps the items that are not divisible by `m`
+    Retain(i32),
+    /// `dedup_by_key(|item| item / m)`
+    DedupByKey(i32),
+    /// `split_off(index)`
+    SplitOff(usize),
+    /// `append` with a vector that holds `values`
+    Append(Vec<i32>),
+    /// edits the items in `range` with a [`Cursor`](crate::iter::Cursor)
+    Cursor(Range<usize>, Vec<CursorOp>),
+}
+
+/// An edit made by [`Op::Cursor`]
+///
+/// Edits that are not possible at that point (like taking from an empty
+/// cursor, or writing without an unfilled slot) are skipped, and both
+/// vectors must agree on which edits are possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorOp {
+    /// `take_front()`
+    TakeFront,
+    /// `take_back()`
+    TakeBack,
+    /// `drop_front()`
+    DropFront,
+    /// `drop_back()`
+    DropBack,
+    /// `skip_front()`
+    SkipFront,
+    /// `skip_back()`
+    SkipBack,
+    /// `write_front(value)`
+    WriteFront(i32),
+    /// `write_back(value)`
+    WriteBack(i32),
+}
+
+impl Op {
+    /// An upper bound on how many items this operation adds to the vector
+    fn growth(&self, len: usize) -> usize {
+        match self {
+            Op::Push(_) | Op::Insert(..) => 1,
+            Op::ExtendFromSlice(values) | Op::Append(values) | Op::Splice(_, values) => values.len(),
+            Op::Resize(new_len, _) => new_len.saturating_sub(len),
+            _ => 0,
+        }
+    }
+}
+
+/// A vector that operations can be applied to
+trait Subject {
+    fn items(&self) -> &[Tracked];
+
+    fn apply(&mut self, tracker: &Tracker, op: &Op) -> Vec<i32>;
+}
+
+fn values(items: impl IntoIterator<Item = Tracked>) -> Vec<i32> { items.into_iter().map(|item| item.value()).collect() }
+
+fn items(tracker: &Tracker, values: &[i32]) -> Vec<Tracked> { values.iter().map(|&value| tracker.item(value)).collect() }
+
+impl Subject for Vec<Tracked> {
+    fn items(&self) -> &[Tracked] { self }
+
+    fn apply(&mut self, tracker: &Tracker, op: &Op) -> Vec<i32> {
+        match op {
+            Op::Push(value) => self.push(tracker.item(*value)),
+            Op::Pop => return values(self.pop()),
+            Op::Insert(index, value) => self.insert(*index, tracker.item(*value)),
+            Op::Remove(index) => return values(Some(self.remove(*index))),
+            Op::SwapRemove(index) => return values(Some(self.swap_remove(*index))),
+            Op::Truncate(len) => self.truncate(*len),
+            Op::Clear => self.clear(),
+            Op::ExtendFromSlice(values) => self.extend_from_slice(&items(tracker, values)),
+            Op::Resize(len, value) => self.resize(*len, tracker.item(*value)),
+            Op::Drain(range) => return values(self.drain(range.clone())),
+            Op::DrainFilter(range, m) => {
+                let _ = &self[range.clone()];
+                let mut removed = Vec::new();
+                let mut index = range.start;
+                for _ in range.clone() {
+                    if self[index].value() % m == 0 {
+                        removed.push(self.remove(index));
+                    } else {
+                        index += 1;
+                    }
+                }
+                return values(removed)
+            }
+            Op::Splice(range, new) => return values(self.splice(range.clone(), items(tracker, new))),
+            Op::Retain(m) => self.retain(|item| item.value() % m != 0),
+            Op::DedupByKey(m) => self.dedup_by_key(|item| item.value() / m),
+            Op::SplitOff(index) => return values(self.split_off(*index)),
+            Op::Append(values) => self.append(&mut items(tracker, values)),
+            Op::Cursor(range, ops) => return model_cursor(self, tracker, range.clone(), ops),
+        }
+
+        Vec::new()
+    }
+}
+
+/// Applies the cursor edits to a [`Vec`], by keeping track of the unfilled slots
+fn model_cursor(vec: &mut Vec<Tracked>, tracker: &Tracker, range: Range<usize>, ops: &[CursorOp]) -> Vec<i32> {
+    let _ = &vec[range.clone()];
+    let mut suffix = vec.split_off(range.end);
+    let mut rest = vec.drain(range.start..).collect::<VecDeque<_>>();
+    let (mut front, mut back) = (Vec::new(), Vec::new());
+    let (mut front_slots, mut back_slots) = (0_usize, 0_usize);
+    let mut output = Vec::new();
+
+    for op in ops {
+        match *op {
+            CursorOp::TakeFront | CursorOp::DropFront if !rest.is_empty() => {
+                let item = rest.pop_front().unwrap();
+                if *op == CursorOp::TakeFront {
+                    output.push(item.value());
+                }
+                front_slots += 1;
+            }
+            CursorOp::TakeBack | CursorOp::DropBack if !rest.is_empty() => {
+                let item = rest.pop_back().unwrap();
+                if *op == CursorOp::TakeBack {
+                    output.push(item.value());
+                }
+                back_slots += 1;
+            }
+            CursorOp::SkipFront if !rest.is_empty() => front.push(rest.pop_front().unwrap()),
+            CursorOp::SkipBack if !rest.is_empty() => back.push(rest.pop_back().unwrap()),
+            // once the cursor is empty, all of the unfilled slots are in one gap
+            CursorOp::WriteFront(value) if front_slots > 0 || (rest.is_empty() && back_slots > 0) => {
+                if front_slots > 0 {
+                    front_slots -= 1;
+                } else {
+                    back_slots -= 1;
+                }
+                front.push(tracker.item(value));
+                output.push(value);
+            }
+            CursorOp::WriteBack(value) if back_slots > 0 || (rest.is_empty() && front_slots > 0) => {
+                if back_slots > 0 {
+                    back_slots -= 1;
+                } else {
+                    front_slots -= 1;
+                }
+                back.push(tracker.item(value));
+                output.push(value);
+            }
+            _ => (),
+        }
+    }
+
+    vec.extend(front);
+    vec.extend(rest);
+    vec.extend(back.into_iter().rev());
+    vec.append(&mut suffix);
+    output
+}
+
+impl<S: ?Sized + Storage<Item = Tracked>> Subject for GenericVec<Tracked, S> {
+    fn items(&self) -> &[Tracked] { self }
+
+    fn apply(&mut self, tracker: &Tracker, op: &Op) -> Vec<i32> {
+        match op {
+            Op::Push(value) => {
+                self.push(tracker.item(*value));
+            }
+            Op::Pop => return values(self.try_pop()),
+            Op::Insert(index, value) => {
+                self.insert(*index, tracker.item(*value));
+            }
+            Op::Remove(index) => return values(Some(self.remove(*index))),
+            Op::SwapRemove(index) => return values(Some(self.swap_remove(*index))),
+            Op::Truncate(len) => self.truncate(*len),
+            Op::Clear => self.clear(),
+            Op::ExtendFromSlice(values) => self.extend_from_slice(&items(tracker, values)),
+            Op::Resize(len, value) => self.resize(*len, tracker.item(*value)),
+            Op::Drain(range) => return values(self.drain(range.clone())),
+            Op::DrainFilter(range, m) => return values(self.drain_filter(range.clone(), |item| item.value() % m == 0)),
+            Op::Splice(range, new) => return values(self.splice(range.clone(), items(tracker, new))),
+            Op::Retain(m) => self.retain(|item| item.value() % m != 0),
+            Op::DedupByKey(m) => self.dedup_by_key(|item| item.value() / m),
+            Op::SplitOff(index) => return values(self.split_off::<std::boxed::Box<[_]>>(*index)),
+            Op::Append(values) => self.append(&mut HeapVec::from(items(tracker, values))),
+            Op::Cursor(range, ops) => {
+                let mut cursor = self.cursor(range.clone());
+                let mut output = Vec::new();
+
+                for op in ops {
+                    match *op {
+                        CursorOp::TakeFront if !cursor.is_empty() => output.push(cursor.take_front().value()),
+                        CursorOp::TakeBack if !cursor.is_empty() => output.push(cursor.take_back().value()),
+                        CursorOp::DropFront if !cursor.is_empty() => cursor.drop_front(),
+                        CursorOp::DropBack if !cursor.is_empty() => cursor.drop_back(),
+                        CursorOp::SkipFront if !cursor.is_empty() => cursor.skip_front(),
+                        CursorOp::SkipBack if !cursor.is_empty() => cursor.skip_back(),
+                        CursorOp::WriteFront(value) if !cursor.is_write_front_empty() => {
+                            cursor.write_front(tracker.item(value));
+                            output.push(value);
+                        }
+                        CursorOp::WriteBack(value) if !cursor.is_write_back_empty() => {
+                            cursor.write_back(tracker.item(value));
+                            output.push(value);
+                        }
+                        _ => (),
+                    }
+                }
+
+                return output
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+/// The state of one of the vectors after an operation
+#[derive(Debug, PartialEq)]
+struct Outcome {
+    output: Option<Vec<i32>>,
+    items: Vec<i32>,
+    live: usize,
+    dropped: usize,
+}
+
+fn step<V: ?Sized + Subject>(subject: &mut V, tracker: &Tracker, op: &Op) -> Outcome {
+    let output = catch_unwind(AssertUnwindSafe(|| subject.apply(tracker, op))).ok();
+    let items = subject.items().iter().map(Tracked::value).collect::<Vec<_>>();
+
+    Outcome {
+        output,
+        items,
+        live: tracker.live(),
+        dropped: tracker.dropped(),
+    }
+}
+
+fn describe(ops: &[Op], index: usize) -> String {
+    let start = index.saturating_sub(8);
+    let mut history = String::new();
+    for (i, op) in ops.iter().enumerate().take(index + 1).skip(start) {
+        let _ = write!(history, "\n  {i}: {op:?}");
+    }
+    history
+}
+
+/// Applies the operations to `vec` and to a [`Vec`], and asserts that after each operation
+/// both vectors hold the same items, returned the same items, panicked in the same
+/// operations, and dropped the same number of items
+///
+/// `vec` must be empty. Operations that would need more capacity than `vec` can
+/// reserve are skipped, so this works with storages that have a fixed capacity.
+/// Operations that are expected to panic (like removing an out of bounds index)
+/// are checked as well, so some panic messages will be printed.
+///
+/// ```rust
+/// use cl_generic_vec::{testing::{check_ops, Op}, ArrayVec};
+///
+/// let mut vec = ArrayVec::<_, 8>::new();
+/// check_ops(&mut vec, &[Op::Push(1), Op::Insert(0, 2), Op::Drain(0..1), Op::Push(3)]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `vec` is not empty, or if the vectors disagree after some operation
+pub fn check_ops<S: ?Sized + Storage<Item = Tracked>>(vec: &mut GenericVec<Tracked, S>, ops: &[Op]) {
+    assert!(vec.is_empty(), "the operations must be applied to an empty vector");
+
+    let (tracker, model_tracker) = (Tracker::new(), Tracker::new());
+    let mut model = Vec::new();
+
+    for (index, op) in ops.iter().enumerate() {
+        if vec.try_reserve(op.growth(vec.len())).is_err() {
+            continue
+        }
+
+        let outcome = step(vec, &tracker, op);
+        let expected = step(&mut model, &model_tracker, op);
+
+        assert!(
+            outcome == expected,
+            "the vector and the model disagree after the operation {index}\n  vector: {outcome:?}\n  model:  {expected:?}\nrecent operations:{}",
+            describe(ops, index),
+        );
+        assert!(
+            outcome.live == outcome.items.len(),
+            "the vector holds {} items, but {} are alive after the operation {index}\nrecent operations:{}",
+            outcome.items.len(),
+            outcome.live,
+            describe(ops, index),
+        );
+    }
+
+    vec.clear();
+    tracker.assert_live(0);
+}
+
+/// A small deterministic pseudo random number generator ([SplitMix64](https://prng.di.unimi.it/splitmix64.c))
+///
+/// The same seed always generates the same operations, on every platform
+#[derive(Debug, Clone)]
+pub struct OpGenerator {
+    state: u64,
+}
+
+impl OpGenerator {
+    /// Create a new generator from a seed
+    pub fn new(seed: u64) -> Self { Self { state: seed } }
+
+    /// Generate the next random number
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Generate a random number in `0..n`
+    #[allow(clippy::cast_possible_truncation)]
+    fn below(&mut self, n: usize) -> usize { (self.next_u64() % n as u64) as usize }
+
+    /// Generate a small random value, so that duplicates are common
+    #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+    fn value(&mut self) -> i32 { self.below(10) as i32 }
+
+    fn values(&mut self, max: usize) -> Vec<i32> {
+        let len = self.below(max + 1);
+        (0..len).map(|_| self.value()).collect()
+    }
+
+    fn divisor(&mut self) -> i32 { self.value() % 4 + 1 }
+
+    /// A random index in `0..=len`, which is out of bounds once in a while
+    fn index(&mut self, len: usize) -> usize {
+        if self.below(32) == 0 {
+            len + 1 + self.below(2)
+        } else {
+            self.below(len + 1)
+        }
+    }
+
+    fn range(&mut self, len: usize) -> Range<usize> {
+        let (a, b) = (self.index(len), self.index(len));
+        if self.below(32) == 0 {
+            b.max(a)..b.min(a)
+        } else {
+            a.min(b)..a.max(b)
+        }
+    }
+
+    /// Generate a random operation for a vector with `len` items
+    pub fn next_op(&mut self, len: usize) -> Op {
+        // keep the vectors short, so that edge cases are common
+        let grow = len < 24;
+
+        match self.below(17) {
+            0 | 1 if grow => Op::Push(self.value()),
+            3 if grow => Op::Insert(self.index(len), self.value()),
+            4 => Op::Remove(self.index(len.saturating_sub(1))),
+            5 => Op::SwapRemove(self.index(len.saturating_sub(1))),
+            6 => Op::Truncate(self.below(len + 2)),
+            7 if self.below(4) == 0 => Op::Clear,
+            7 | 8 if grow => Op::ExtendFromSlice(self.values(6)),
+            9 => Op::Resize(self.below(len + 4), self.value()),
+            10 => Op::Drain(self.range(len)),
+            11 => Op::DrainFilter(self.range(len), self.divisor()),
+            12 => Op::Splice(self.range(len), self.values(4)),
+            13 => Op::Retain(self.divisor()),
+            14 => Op::DedupByKey(self.divisor()),
+            15 if self.below(2) == 0 => Op::SplitOff(self.index(len)),
+            15 if grow => Op::Append(self.values(4)),
+            16 => {
+                let count = self.below(8);
+                let ops = (0..count)
+                    .map(|_| match self.below(8) {
+                        0 => CursorOp::TakeFront,
+                        1 => CursorOp::TakeBack,
+                        2 => CursorOp::DropFront,
+                        3 => CursorOp::DropBack,
+                        4 => CursorOp::SkipFront,
+                        5 => CursorOp::SkipBack,
+                        6 => CursorOp::WriteFront(self.value()),
+                        _ => CursorOp::WriteBack(self.value()),
+                    })
+                    .collect();
+                Op::Cursor(self.range(len), ops)
+            }
+            _ => Op::Pop,
+        }
+    }
+
+    /// Generate `count` random operations, starting from an empty vector
+    pub fn ops(&mut self, count: usize) -> Vec<Op> {
+        let mut model = Vec::new();
+        let tracker = Tracker::new();
+        let mut ops = vec![];
+
+        for _ in 0..count {
+            let op = self.next_op(model.len());
+            let _ = catch_unwind(AssertUnwindSafe(|| model.apply(&tracker, &op)));
+            ops.push(op);
+        }
+
+        ops
+    }
+}
+
+/// Generates `count` random operations from `seed` with [`OpGenerator`], and checks them with [`check_ops`]
+///
+/// ```rust
+/// use cl_generic_vec::{testing::check_random, HeapVec};
+///
+/// for seed in 0..4 {
+///     check_random(&mut HeapVec::new(), seed, 100);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `vec` is not empty, or if the vectors disagree after some operation
+pub fn check_random<S: ?Sized + Storage<Item = Tracked>>(vec: &mut GenericVec<Tracked, S>, seed: u64, count: usize) {
+    let ops = OpGenerator::new(seed).ops(count);
+    check_ops(vec, &ops);
+}
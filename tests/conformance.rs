@@ -0,0 +1,55 @@
+#![cfg(all(feature = "testing", not(feature = "no-panic")))]
+
+mod array_vec {
+    cl_generic_vec::conformance_tests!(cl_generic_vec::uninit_array::<_, 16>());
+}
+
+mod heap_vec {
+    cl_generic_vec::conformance_tests!(std::boxed::Box::<[_]>::default());
+}
+
+mod arc_vec {
+    cl_generic_vec::conformance_tests!(cl_generic_vec::raw::ArcStorage::new());
+}
+
+mod checked_poison_vec {
+    cl_generic_vec::conformance_tests!(cl_generic_vec::raw::Checked::new(cl_generic_vec::raw::Poison::<
+        std::boxed::Box<[_]>,
+        true,
+    >::default()));
+}
+
+mod slice_vec {
+    macro_rules! slice_vec {
+        ($vec:pat, max($len:expr)) => {
+            let mut buf = cl_generic_vec::uninit_array::<_, $len>();
+            let $vec = unsafe { cl_generic_vec::SliceVec::new(&mut buf) };
+        };
+    }
+
+    cl_generic_vec::conformance_tests!(macro slice_vec);
+}
+
+#[test]
+fn model_heap_vec() {
+    for seed in 0..64 {
+        cl_generic_vec::testing::check_random(&mut cl_generic_vec::HeapVec::new(), seed, 200);
+    }
+}
+
+#[test]
+fn model_array_vec() {
+    for seed in 0..64 {
+        cl_generic_vec::testing::check_random(&mut cl_generic_vec::ArrayVec::<_, 16>::new(), seed, 200);
+    }
+}
+
+#[test]
+fn model_checked_poison_vec() {
+    use cl_generic_vec::raw::{Checked, Poison};
+
+    for seed in 0..16 {
+        let mut vec = cl_generic_vec::GenericVec::with_storage(Checked::new(Poison::<std::boxed::Box<[_]>, true>::default()));
+        cl_generic_vec::testing::check_random(&mut vec, seed, 200);
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "no-panic")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc as std;
+
+use cl_generic_vec::ArrayVec;
+
+#[test]
+fn array_vec() {
+    let mut vec = ArrayVec::<u32, 2>::new();
+    assert!(vec.try_push(1).is_ok());
+    assert!(vec.try_insert(0, 0).is_ok());
+    assert_eq!(vec.try_push(2), Err(2));
+    assert!(vec.try_reserve(1).is_err());
+    assert_eq!(vec, [0, 1]);
+
+    assert!(vec.try_extend([2]).is_err());
+    assert!(vec.try_extend_from_slice(&[2]).is_err());
+    assert_eq!(vec.try_resize(3, 2), Err(2));
+
+    assert_eq!(vec.try_swap_remove(0), Some(0));
+    assert_eq!(vec.try_pop(), Some(1));
+    assert_eq!(vec.try_pop(), None);
+}
+
+#[test]
+fn const_array_vec() {
+    const VEC: ArrayVec<u32, 4> = {
+        let mut vec = ArrayVec::new();
+        assert!(vec.try_extend_from_array([1, 2]).is_ok());
+        assert!(vec.const_try_push(3).is_ok());
+        assert!(vec.try_extend_from_array([4, 5]).is_err());
+        vec
+    };
+    assert_eq!(VEC, [1, 2, 3]);
+    assert!(ArrayVec::<u32, 1>::try_from_partial_array([1, 2]).is_err());
+}
+
+#[test]
+fn collect() {
+    use cl_generic_vec::iter::IteratorExt;
+
+    let (vec, rest) = (0..3).try_collect_into::<ArrayVec<_, 2>>().unwrap_err();
+    assert_eq!(vec, [0, 1]);
+    assert!(rest.eq([2]));
+    assert_eq!((0..3).collect_truncating::<ArrayVec<_, 2>>(), [0, 1]);
+    assert_eq!(ArrayVec::<_, 3>::from_fn(|i| i), [0, 1, 2]);
+}
+
+#[test]
+fn slice_vec() {
+    let mut buffer = cl_generic_vec::uninit_array::<u8, 4>();
+    let mut vec = unsafe { cl_generic_vec::SliceVec::new(&mut buffer) };
+    assert!(vec.try_reserve(4).is_ok());
+    unsafe { vec.extend_from_slice_unchecked(&[1, 2, 3, 4]) };
+    assert!(vec.try_reserve(1).is_err());
+    assert!(vec.drain(1..3).eq([2, 3]));
+    assert_eq!(vec, [1, 4]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn heap_vec() {
+    let mut vec = cl_generic_vec::HeapVec::<u64>::new();
+    assert!(vec.try_reserve(3).is_ok());
+    for i in 0..3 {
+        assert!(vec.try_push(i).is_ok());
+    }
+    assert!(vec.try_reserve(usize::MAX).is_err());
+    assert!(vec.try_extend_from_slice(&[3, 4]).is_ok());
+    assert_eq!(vec, [0, 1, 2, 3, 4]);
+    assert_eq!(vec.try_split_off::<std::boxed::Box<[_]>>(3).unwrap(), [3, 4]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn shared_vec() {
+    let mut vec = cl_generic_vec::ArcVec::<u64>::new();
+    assert!(vec.try_reserve(2).is_ok());
+    assert!(vec.try_push(0).is_ok());
+    let shared = vec.clone();
+    assert!(vec.storage().is_shared());
+
+    assert!(vec.try_push(1).is_ok());
+    assert!(!vec.storage().is_shared());
+    assert_eq!(vec, [0, 1]);
+    assert_eq!(shared, [0]);
+
+    let mut other = vec.clone();
+    assert_eq!(other.try_pop(), Some(1));
+    assert_eq!(vec, [0, 1]);
+    assert_eq!(other, [0]);
+}
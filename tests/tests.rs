@@ -45,32 +45,172 @@ mod to_string {
     impl TestToString for &&str {}
 }
 
-macro_rules! imp_make_tests_files {
-    ($(#[$meta:meta])*mod $mod:ident {
-        $($ident:ident),* $(,)?
-    }) => {
-        $(#[$meta])*
-        mod $mod {
-            $(
-                mod $ident {
-                    include!(concat!("template/", stringify!($mod), "/", stringify!($ident), ".rs"));
-                }
-            )*
-        }
+/// The panicking methods used by the test templates, written in terms of the
+/// fallible methods that are left with the `no-panic` feature, so that the
+/// templates exercise the same code paths with the feature enabled
+#[cfg(feature = "no-panic")]
+mod no_panic {
+    use cl_generic_vec::{
+        raw::{Storage, StorageWithCapacity},
+        ArrayVec, GenericVec, SimpleVec,
     };
+    use core::ops::RangeBounds;
+
+    #[track_caller]
+    fn fail() -> ! {
+        panic!("ran out of capacity")
+    }
+
+    pub trait PanicVec<S: ?Sized + Storage> {
+        fn push(&mut self, value: S::Item) -> &mut S::Item;
+        fn push_array<const N: usize>(&mut self, value: [S::Item; N]) -> &mut [S::Item; N];
+        fn insert(&mut self, index: usize, value: S::Item) -> &mut S::Item;
+        fn insert_array<const N: usize>(&mut self, index: usize, value: [S::Item; N]) -> &mut [S::Item; N];
+        fn reserve(&mut self, additional: usize);
+        fn extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I);
+        fn extend_from_slice(&mut self, slice: &[S::Item])
+        where
+            S::Item: Clone;
+        fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+        where
+            S::Item: Clone;
+        fn grow(&mut self, additional: usize, value: S::Item)
+        where
+            S::Item: Clone;
+        fn split_off_into<B: ?Sized + Storage<Item = S::Item>>(&mut self, index: usize, other: &mut GenericVec<S::Item, B>);
+        fn retain_into<B, F>(&mut self, other: &mut GenericVec<S::Item, B>, f: F)
+        where
+            B: ?Sized + Storage<Item = S::Item>,
+            F: FnMut(&mut S::Item) -> bool;
+    }
+
+    impl<S: ?Sized + Storage> PanicVec<S> for GenericVec<S::Item, S> {
+        #[track_caller]
+        fn push(&mut self, value: S::Item) -> &mut S::Item {
+            self.reserve(1);
+            self.try_push(value).unwrap_or_else(|_| fail())
+        }
+
+        #[track_caller]
+        fn push_array<const N: usize>(&mut self, value: [S::Item; N]) -> &mut [S::Item; N] {
+            self.reserve(N);
+            self.try_push_array(value).unwrap_or_else(|_| fail())
+        }
+
+        #[track_caller]
+        fn insert(&mut self, index: usize, value: S::Item) -> &mut S::Item {
+            assert!(index <= self.len(), "Tried to insert at {index}, but length is {}", self.len());
+            self.reserve(1);
+            self.try_insert(index, value).unwrap_or_else(|_| fail())
+        }
+
+        #[track_caller]
+        fn insert_array<const N: usize>(&mut self, index: usize, value: [S::Item; N]) -> &mut [S::Item; N] {
+            assert!(index <= self.len(), "Tried to insert at {index}, but length is {}", self.len());
+            self.reserve(N);
+            self.try_insert_array(index, value).unwrap_or_else(|_| fail())
+        }
+
+        #[track_caller]
+        fn reserve(&mut self, additional: usize) {
+            if self.try_reserve(additional).is_err() {
+                fail()
+            }
+        }
+
+        #[track_caller]
+        fn extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) {
+            let iter = iter.into_iter();
+            self.reserve(iter.size_hint().0);
+            if self.try_extend(iter).is_err() {
+                fail()
+            }
+        }
+
+        #[track_caller]
+        fn extend_from_slice(&mut self, slice: &[S::Item])
+        where
+            S::Item: Clone,
+        {
+            if self.try_extend_from_slice(slice).is_err() {
+                fail()
+            }
+        }
+
+        #[track_caller]
+        fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+        where
+            S::Item: Clone,
+        {
+            if self.try_extend_from_within(src).is_err() {
+                fail()
+            }
+        }
+
+        #[track_caller]
+        fn grow(&mut self, additional: usize, value: S::Item)
+        where
+            S::Item: Clone,
+        {
+            if self.try_grow(additional, value).is_err() {
+                fail()
+            }
+        }
+
+        #[track_caller]
+        fn split_off_into<B: ?Sized + Storage<Item = S::Item>>(&mut self, index: usize, other: &mut GenericVec<S::Item, B>) {
+            assert!(index <= self.len(), "Tried to split at index {index}, but length is {}", self.len());
+            other.reserve(self.len() - index);
+            if other.try_extend(self.drain(index..)).is_err() {
+                fail()
+            }
+        }
+
+        #[track_caller]
+        fn retain_into<B, F>(&mut self, other: &mut GenericVec<S::Item, B>, f: F)
+        where
+            B: ?Sized + Storage<Item = S::Item>,
+            F: FnMut(&mut S::Item) -> bool,
+        {
+            if self.try_retain_into(other, f).is_err() {
+                fail()
+            }
+        }
+    }
+
+    pub trait PanicArrayVec<T, const N: usize> {
+        fn from_partial_array<const M: usize>(array: [T; M]) -> Self;
+    }
+
+    impl<T, const N: usize> PanicArrayVec<T, N> for ArrayVec<T, N> {
+        #[track_caller]
+        fn from_partial_array<const M: usize>(array: [T; M]) -> Self {
+            Self::try_from_partial_array(array).unwrap_or_else(|_| fail())
+        }
+    }
+
+    pub trait PanicWithCapacity {
+        fn with_capacity(capacity: usize) -> Self;
+    }
+
+    impl<S: StorageWithCapacity> PanicWithCapacity for SimpleVec<S> {
+        #[track_caller]
+        fn with_capacity(capacity: usize) -> Self { Self::try_with_capacity(capacity).unwrap_or_else(|_| fail()) }
+    }
 }
 
+#[cfg(feature = "no-panic")]
+use no_panic::{PanicArrayVec as _, PanicVec as _, PanicWithCapacity as _};
+
 macro_rules! make_tests_files {
     () => {
-        make_tests_files! { copy_only }
-        imp_make_tests_files! {
-            #[cfg(feature = "alloc")]
-            mod owned { simple, into_iter, cursor, drain, splice, vec_ops }
-        }
-    };
-    (copy_only) => {
-        imp_make_tests_files! {
-            mod copy { simple, into_iter, cursor, drain, splice, vec_ops }
+        cl_generic_vec::__template_tests! {
+            #[allow(unused_imports)]
+            use mockalloc::test as template_test;
+
+            #[cfg(feature = "no-panic")]
+            #[allow(unused_imports)]
+            use crate::no_panic::{PanicArrayVec as _, PanicVec as _, PanicWithCapacity as _};
         }
     };
 }
@@ -112,6 +252,503 @@ mod heap_vec {
     make_tests_files!();
 }
 
+#[cfg(feature = "alloc")]
+mod arc_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::ArcVec::new();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+mod rc_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::RcVec::new();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+#[mockalloc::test]
+#[cfg(not(feature = "no-panic"))]
+fn shared_vec_copy_on_write() {
+    use cl_generic_vec::{ArcVec, HeapVec};
+
+    let mut heap = HeapVec::new();
+    heap.extend((0..4).map(|x| S!(x)));
+    let ptr = heap.as_ptr();
+
+    let mut a = ArcVec::from(heap);
+    assert_eq!(a.as_ptr(), ptr);
+
+    let b = a.clone();
+    let c = b.clone();
+    assert_eq!(b.as_ptr(), ptr);
+    assert!(a.storage().is_shared());
+
+    a.push(S!(4));
+    assert_ne!(a.as_ptr(), ptr);
+    assert_eq!(a, S!([0, 1, 2, 3, 4]));
+    assert_eq!(b, S!([0, 1, 2, 3]));
+
+    drop(b);
+    let mut c = HeapVec::from(c);
+    assert_eq!(c.as_ptr(), ptr);
+    c.truncate(1);
+    assert_eq!(c, S!([0]));
+
+    let d = a.clone();
+    assert_eq!(d.into_iter().collect::<HeapVec<_>>(), S!([0, 1, 2, 3, 4]));
+    assert!(!a.storage().is_shared());
+}
+
+mod zeroizing_array_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            #[cfg(feature = "alloc")]
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Zeroizing<[_; $len]>>::new();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+mod zeroizing_heap_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Zeroizing<std::boxed::Box<[_]>>>::default();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[test]
+fn zeroizing_release() {
+    use cl_generic_vec::raw::Zeroizing;
+
+    fn is_zeroed(slots: &[MaybeUninit<u8>]) -> bool { slots.iter().all(|b| unsafe { b.assume_init() } == 0) }
+
+    let mut vec = GenericVec::<u8, Zeroizing<[_; 8]>>::new();
+    vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    vec.truncate(6);
+    assert!(is_zeroed(&vec.storage().as_ref()[6..]));
+    assert_eq!(vec.pop(), 6);
+    assert!(is_zeroed(&vec.storage().as_ref()[5..]));
+    assert_eq!(vec.remove(0), 1);
+    assert!(is_zeroed(&vec.storage().as_ref()[4..]));
+    vec.drain(1..3);
+    assert_eq!(vec, [2, 5]);
+    assert!(is_zeroed(&vec.storage().as_ref()[2..]));
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.as_slice(), [5]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn poison_extend_from_within_panic() {
+    use cl_generic_vec::raw::Poison;
+
+    #[derive(Debug, PartialEq)]
+    struct PanicOnClone(u8);
+
+    impl Clone for PanicOnClone {
+        fn clone(&self) -> Self {
+            assert!(self.0 != 0, "oops");
+            Self(self.0)
+        }
+    }
+
+    let mut vec = GenericVec::<_, Poison<[_; 8], true>>::new();
+    vec.push(PanicOnClone(1));
+    vec.push(PanicOnClone(0));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| vec.extend_from_within(..)));
+    assert!(result.is_err());
+    assert_eq!(vec, [PanicOnClone(1), PanicOnClone(0)]);
+
+    vec.push(PanicOnClone(2));
+    vec.push(PanicOnClone(3));
+    assert_eq!(vec, [PanicOnClone(1), PanicOnClone(0), PanicOnClone(2), PanicOnClone(3)]);
+}
+
+#[test]
+#[cfg(all(feature = "std", not(feature = "no-panic")))]
+fn poison_clone_panic() {
+    use cl_generic_vec::raw::Poison;
+
+    #[derive(Debug, PartialEq)]
+    struct PanicOnClone(u8);
+
+    impl Clone for PanicOnClone {
+        fn clone(&self) -> Self {
+            assert!(self.0 != 0, "oops");
+            Self(self.0)
+        }
+    }
+
+    let mut vec = GenericVec::<_, Poison<[_; 8], true>>::new();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.extend_from_slice(&[PanicOnClone(1), PanicOnClone(0)]);
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec, []);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| vec.resize(3, PanicOnClone(0))));
+    assert!(result.is_err());
+    assert_eq!(vec, []);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut made = 0;
+        vec.grow_with(3, || {
+            made += 1;
+            assert!(made < 2, "oops");
+            PanicOnClone(made)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec, []);
+
+    vec.push(PanicOnClone(3));
+    assert_eq!(vec, [PanicOnClone(3)]);
+}
+
+mod poison_array_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            #[cfg(feature = "alloc")]
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Poison<[_; $len], true>>::new();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+mod poison_heap_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Poison<std::boxed::Box<[_]>, true>>::default();
+        };
+    }
+
+    make_tests_files!();
+}
+
+mod checked_array_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            #[cfg(feature = "alloc")]
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Checked<[_; $len]>>::new();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+mod checked_heap_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Checked<std::boxed::Box<[_]>>>::default();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+mod checked_arc_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::with_storage(cl_generic_vec::raw::Checked::new(
+                cl_generic_vec::raw::ArcStorage::new(),
+            ));
+        };
+    }
+
+    make_tests_files!();
+}
+
+/// A storage that forgets to grow when it is asked to
+struct LazyStorage([MaybeUninit<u8>; 4]);
+
+impl AsRef<[MaybeUninit<u8>]> for LazyStorage {
+    fn as_ref(&self) -> &[MaybeUninit<u8>] { &self.0 }
+}
+
+impl AsMut<[MaybeUninit<u8>]> for LazyStorage {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<u8>] { &mut self.0 }
+}
+
+unsafe impl cl_generic_vec::raw::Storage for LazyStorage {
+    type Item = u8;
+
+    fn reserve(&mut self, _new_capacity: usize) {}
+
+    fn try_reserve(&mut self, _new_capacity: usize) -> cl_generic_vec::raw::AllocResult { Ok(()) }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn checked_storage_contract() {
+    use cl_generic_vec::raw::Checked;
+
+    let mut vec = GenericVec::with_storage(Checked::new(LazyStorage(cl_generic_vec::uninit_array())));
+    vec.extend_from_slice(&[1, 2, 3, 4]);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| vec.reserve(1)));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| vec.try_reserve(1)));
+    assert!(result.is_err());
+}
+
+mod instrumented_array_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            #[cfg(feature = "alloc")]
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Instrumented<[_; $len]>>::new();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(feature = "alloc")]
+mod instrumented_heap_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::Instrumented<std::boxed::Box<[_]>>>::default();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[test]
+fn instrumented_array_stats() {
+    use cl_generic_vec::raw::Instrumented;
+
+    let mut vec = GenericVec::<u32, Instrumented<[MaybeUninit<u32>; 4]>>::new();
+    vec.extend_from_slice(&[1, 2, 3]);
+    vec.pop();
+    vec.push(4);
+    assert!(vec.try_reserve(2).is_err());
+
+    let stats = vec.storage().stats();
+    assert_eq!(stats.try_reserves, 1);
+    assert_eq!(stats.failed_reservations, 1);
+    assert_eq!(stats.reallocations, 0);
+    assert_eq!(stats.bytes_allocated, 0);
+    assert_eq!(stats.max_capacity, 4);
+    assert_eq!(stats.max_len, 3);
+}
+
+#[test]
+fn instrumented_tail_vec_stats() {
+    use cl_generic_vec::raw::Instrumented;
+
+    let mut vec = GenericVec::<u32, Instrumented<[MaybeUninit<u32>; 16]>>::new();
+    vec.push(1);
+    vec.tail_vec();
+    assert_eq!(vec.storage().stats().max_len, 1);
+
+    let mut tail = vec.tail_vec();
+    tail.push(2);
+    tail.push(3);
+    drop(tail);
+    assert_eq!(vec, [1, 2, 3]);
+    assert_eq!(vec.storage().stats().max_len, 3);
+}
+
+#[test]
+#[cfg(all(feature = "std", not(feature = "no-panic")))]
+fn instrumented_shared_stats() {
+    use cl_generic_vec::raw::{Instrumented, SharedStats, Stats, StatsSink};
+
+    static STATS: SharedStats = SharedStats::new();
+
+    #[derive(Default)]
+    struct Global;
+
+    impl StatsSink for Global {
+        fn report(&self, stats: &Stats) { STATS.report(stats) }
+    }
+
+    {
+        let mut vec = GenericVec::with_storage(Instrumented::with_sink(std::boxed::Box::<[_]>::default(), &STATS));
+        vec.extend_from_slice(&[1_u64, 2, 3]);
+        vec.reserve(100);
+        assert_eq!(vec.storage().stats().reallocations, 2);
+    }
+
+    {
+        let mut vec = GenericVec::<u64, Instrumented<std::boxed::Box<[_]>, Global>>::with_capacity(10);
+        vec.extend(0..10);
+    }
+
+    let stats = STATS.snapshot();
+    assert_eq!(stats.reserves, 2);
+    assert_eq!(stats.reallocations, 2);
+    assert_eq!(stats.max_capacity, 103);
+    assert_eq!(stats.max_len, 10);
+    assert_eq!(stats.bytes_allocated, stats.bytes_freed);
+    assert_eq!(stats.bytes_allocated, 8 * (stats.max_capacity + 10 + 4));
+
+    STATS.reset();
+    assert_eq!(STATS.snapshot(), Stats::default());
+}
+
+#[cfg(all(feature = "guard-pages", target_os = "linux"))]
+mod guarded_end_heap_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::<_, cl_generic_vec::raw::GuardedHeap<_>>::default();
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[cfg(all(feature = "guard-pages", target_os = "linux"))]
+mod guarded_start_heap_vec {
+    macro_rules! new_vec {
+        ($vec:pat, max($len:expr)) => {
+            let _bump = std::boxed::Box::new(1);
+            let $vec = cl_generic_vec::GenericVec::with_storage(cl_generic_vec::raw::GuardedHeap::new(
+                cl_generic_vec::raw::GuardAlign::Start,
+            ));
+        };
+    }
+
+    make_tests_files!();
+}
+
+#[test]
+#[cfg(all(feature = "guard-pages", target_os = "linux"))]
+fn guarded_heap_placement() {
+    use cl_generic_vec::raw::{GuardAlign, GuardedHeap};
+
+    const PAGE: usize = 4096;
+
+    let mut end = GenericVec::with_storage(GuardedHeap::new(GuardAlign::End));
+    let mut start = GenericVec::with_storage(GuardedHeap::new(GuardAlign::Start));
+
+    for i in 0..3000_u32 {
+        end.push(i);
+        start.push(i);
+        assert_eq!(end.storage().as_ref().as_ptr_range().end as usize % PAGE, 0);
+        assert_eq!(start.storage().as_ref().as_ptr() as usize % PAGE, 0);
+    }
+
+    assert!(end.iter().copied().eq(0..3000));
+    assert!(start.iter().copied().eq(0..3000));
+    assert_eq!(end.storage().guard_align(), GuardAlign::End);
+}
+
+#[test]
+fn poison_release() {
+    use cl_generic_vec::raw::Poison;
+
+    type Storage = Poison<[MaybeUninit<u16>; 8], true>;
+
+    fn is_poisoned(slots: &[MaybeUninit<u16>]) -> bool {
+        let byte = u16::from_ne_bytes([Storage::BYTE; 2]);
+        slots.iter().all(|b| unsafe { b.assume_init() } == byte)
+    }
+
+    let mut vec = GenericVec::<u16, Storage>::new();
+    assert!(is_poisoned(vec.storage().as_ref()));
+    vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    vec.truncate(6);
+    assert!(is_poisoned(&vec.storage().as_ref()[6..]));
+    assert_eq!(vec.pop(), 6);
+    assert!(is_poisoned(&vec.storage().as_ref()[5..]));
+    assert_eq!(vec.remove(0), 1);
+    assert!(is_poisoned(&vec.storage().as_ref()[4..]));
+    vec.drain(1..3);
+    assert_eq!(vec, [2, 5]);
+    assert!(is_poisoned(&vec.storage().as_ref()[2..]));
+
+    let mut other = GenericVec::<u16, Storage>::new();
+    vec.split_off_into(1, &mut other);
+    assert_eq!(other, [5]);
+    assert!(is_poisoned(&vec.storage().as_ref()[1..]));
+    vec.push(9);
+    assert_eq!(vec, [2, 9]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn poison_verify() {
+    use cl_generic_vec::raw::Poison;
+
+    let mut vec = GenericVec::<u8, Poison<[MaybeUninit<u8>; 4], true>>::new();
+    vec.push(1);
+    vec.spare_capacity_mut()[0] = MaybeUninit::new(2);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.push(3);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn poison_tail_vec_swapped_out() {
+    use cl_generic_vec::{raw::Poison, SliceVec};
+    use std::boxed::Box;
+
+    let mut vec = GenericVec::<Box<u64>, Poison<[_; 4], true>>::new();
+    let orig = {
+        let mut tail = vec.tail_vec();
+        tail.push(Box::new(1));
+        core::mem::replace(&mut *tail, unsafe { SliceVec::new(&mut []) })
+    };
+    assert_eq!(*orig[0], 1);
+    drop(orig);
+    assert!(vec.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn zeroizing_tail_vec_swapped_out() {
+    use cl_generic_vec::{raw::Zeroizing, SliceVec};
+    use std::string::String;
+
+    let mut vec = GenericVec::<String, Zeroizing<[_; 4]>>::new();
+    let orig = {
+        let mut tail = vec.tail_vec();
+        tail.push(String::from("live"));
+        core::mem::replace(&mut *tail, unsafe { SliceVec::new(&mut []) })
+    };
+    assert_eq!(orig[0], "live");
+    drop(orig);
+    assert!(vec.is_empty());
+}
+
 #[test]
 fn unsized_slice_vec() {
     let mut array_vec = ArrayVec::<i32, 16>::new();
@@ -128,3 +765,555 @@ fn unsized_slice_vec() {
     assert_eq!(slice_vec.capacity(), 16);
     assert_eq!(*slice_vec, [1, 2]);
 }
+
+#[test]
+fn fallible_array_vec() {
+    let mut vec = ArrayVec::<i32, 4>::new();
+
+    let rest = vec.try_extend(0..6).unwrap_err();
+    assert!(rest.eq([4, 5]));
+    assert_eq!(vec, [0, 1, 2, 3]);
+
+    vec.truncate(2);
+    assert!(vec.try_extend_from_slice(&[4, 5, 6]).is_err());
+    assert!(vec.try_insert_slice(1, &[4, 5, 6]).is_err());
+    assert!(vec.try_insert_slice(3, &[4]).is_err());
+    assert_eq!(vec.try_resize(5, 7), Err(7));
+    assert!(vec.try_resize_with(5, || 7).is_err());
+    assert_eq!(vec.try_grow(3, 7), Err(7));
+    assert_eq!(vec, [0, 1]);
+
+    assert!(vec.try_insert_slice(1, &[4, 5]).is_ok());
+    assert_eq!(vec, [0, 4, 5, 1]);
+
+    assert!(vec.try_split_off::<[_; 2]>(1).is_err());
+    assert_eq!(vec.try_split_off::<[_; 3]>(1).unwrap(), [4, 5, 1]);
+    assert_eq!(vec, [0]);
+
+    assert!(vec.try_resize(3, 9).is_ok());
+    assert_eq!(vec, [0, 9, 9]);
+
+    let vec = vec.try_convert::<[MaybeUninit<_>; 2]>().unwrap_err();
+    assert_eq!(vec, [0, 9, 9]);
+    let vec = vec.try_convert::<[MaybeUninit<_>; 3]>().unwrap();
+    assert_eq!(vec.try_clone().unwrap(), [0, 9, 9]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn fallible_heap_vec() {
+    let mut vec = cl_generic_vec::HeapVec::<u64>::new();
+    assert!(vec.try_extend(0..3).is_ok());
+    assert!(vec.try_reserve(usize::MAX / 2).is_err());
+    assert!(vec.try_grow(usize::MAX / 2, 0).is_err());
+    assert!(vec.try_extend_from_slice(&[3]).is_ok());
+    assert_eq!(vec, [0, 1, 2, 3]);
+
+    assert!(cl_generic_vec::HeapVec::<u64>::try_with_capacity(usize::MAX / 2).is_err());
+    assert_eq!(vec.try_split_off::<std::boxed::Box<[_]>>(2).unwrap(), [2, 3]);
+    assert_eq!(vec.try_clone().unwrap(), [0, 1]);
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn array_vec_array_ops() {
+    let mut vec = ArrayVec::<i32, 4>::new();
+    assert_eq!(vec.try_push_array([1, 2, 3]), Ok(&mut [1, 2, 3]));
+    assert_eq!(vec.try_push_array([4, 5]), Err([4, 5]));
+    assert_eq!(vec.try_insert_array(4, [0]), Err([0]));
+    assert_eq!(vec.try_insert_array(0, [0]), Ok(&mut [0]));
+    assert_eq!(vec, [0, 1, 2, 3]);
+
+    assert_eq!(vec.try_pop_array::<5>(), None);
+    assert_eq!(vec.try_pop_array(), Some([2, 3]));
+    assert_eq!(vec.try_remove_array::<2>(1), None);
+    assert_eq!(vec.try_remove_array(0), Some([0, 1]));
+    assert!(vec.is_empty());
+
+    let vec: ArrayVec<i32, 3> = cl_generic_vec::gvec![1, 2, 3];
+    assert_eq!(<[i32; 3]>::try_from(vec), Ok([1, 2, 3]));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn const_array_vec() {
+    #[derive(Debug, PartialEq)]
+    struct Entry {
+        key: &'static str,
+        value: u32,
+    }
+
+    static TABLE: ArrayVec<Entry, 4> = {
+        let mut vec = ArrayVec::new();
+        vec.const_push(Entry { key: "a", value: 1 });
+        vec.extend_from_array([Entry { key: "b", value: 2 }, Entry { key: "c", value: 3 }]);
+        vec
+    };
+
+    const POPPED: (Option<u8>, Option<u8>, usize) = {
+        let mut vec = ArrayVec::<u8, 2>::from_array([1, 2]);
+        let full = vec.const_try_push(3).is_err();
+        let last = vec.const_pop();
+        let first = vec.const_pop();
+        assert!(full && vec.const_pop().is_none());
+        let len = vec.const_as_slice().len();
+        // the destructor of a vector can't run in const contexts
+        core::mem::forget(vec);
+        (last, first, len)
+    };
+
+    assert_eq!(TABLE.len(), 3);
+    assert_eq!(TABLE[2], Entry { key: "c", value: 3 });
+    assert_eq!(POPPED, (Some(2), Some(1), 0));
+
+    static EMPTY: ArrayVec<u8, 2> = cl_generic_vec::gvec![const:];
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn copy_array_vec() {
+    use cl_generic_vec::CopyArrayVec;
+
+    let mut a = CopyArrayVec::<u32, 4>::new();
+    a.push(1);
+    a.extend([2, 3]);
+
+    let mut b = a;
+    assert_eq!(b.pop(), 3);
+    b.insert(0, 0);
+    assert_eq!(a, [1, 2, 3]);
+    assert_eq!(b, [0, 1, 2]);
+    assert!(b < a);
+
+    let c = Clone::clone(&b);
+    assert_eq!(c, b);
+    assert!(c.into_iter().eq([0, 1, 2]));
+    assert_eq!(c.iter().sum::<u32>(), 3);
+
+    let array_vec: ArrayVec<u32, 4> = a.into();
+    assert_eq!(array_vec, [1, 2, 3]);
+    let d = CopyArrayVec::from(array_vec);
+    assert_eq!(d, a);
+    assert_eq!(CopyArrayVec::from([4, 5]), [4, 5]);
+    assert_eq!(core::mem::size_of_val(&d), core::mem::size_of::<ArrayVec<u32, 4>>());
+}
+
+#[test]
+fn array_vec_conversions() {
+    let slice: &[i32] = &[1, 2, 3];
+    assert_eq!(ArrayVec::<i32, 2>::try_from(slice), Err(slice));
+    let vec = ArrayVec::<i32, 3>::try_from(slice).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+
+    let vec = vec.try_into_capacity::<2>().unwrap_err();
+    let vec = vec.try_into_capacity::<8>().unwrap();
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[cfg(not(feature = "no-panic"))]
+fn heap_vec_conversions() {
+    use cl_generic_vec::HeapVec;
+    use std::{borrow::Cow, boxed::Box, collections::VecDeque, rc::Rc, sync::Arc, vec::Vec};
+
+    let vec: HeapVec<i32> = std::vec![1, 2, 3].into();
+    let ptr = vec.as_ptr();
+    let vec = ArrayVec::<i32, 2>::try_from(vec).unwrap_err();
+    assert_eq!(vec.as_ptr(), ptr);
+    let array = ArrayVec::<i32, 4>::try_from(vec).unwrap();
+    assert_eq!(array, [1, 2, 3]);
+    assert_eq!(ArrayVec::<i32, 2>::try_from(std::vec![1, 2, 3]), Err(std::vec![1, 2, 3]));
+
+    let vec = HeapVec::from(array);
+    assert_eq!(vec.capacity(), 3);
+
+    let boxed = Box::<[i32]>::from(vec);
+    let ptr = boxed.as_ptr();
+    let vec = HeapVec::from(boxed);
+    assert_eq!(vec.as_ptr(), ptr);
+
+    let deque = VecDeque::from(vec);
+    let vec = HeapVec::from(deque);
+    assert_eq!(vec.as_ptr(), ptr);
+
+    let cow = Cow::<[i32]>::from(vec);
+    let vec = HeapVec::from(cow);
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(HeapVec::from(Cow::Borrowed(&[4, 5][..])), [4, 5]);
+
+    let rc = Rc::<[i32]>::from(vec);
+    let arc = Arc::<[i32]>::from(HeapVec::from(rc));
+    let vec = HeapVec::from(arc);
+    assert_eq!(Vec::from(ArrayVec::<i32, 3>::try_from(vec).unwrap()), [1, 2, 3]);
+}
+
+#[test]
+fn slice_vec_into_parts() {
+    let mut buffer = cl_generic_vec::uninit_array::<_, 4>();
+    let mut vec = unsafe { cl_generic_vec::SliceVec::new(&mut buffer) };
+    vec.extend([1, 2]);
+
+    let (items, spare) = vec.into_parts();
+    items[0] = 3;
+    assert_eq!(items, [3, 2]);
+    assert_eq!(spare.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn heap_vec_ownership() {
+    use cl_generic_vec::HeapVec;
+    use std::boxed::Box;
+
+    let mut vec = HeapVec::with_capacity(8);
+    vec.extend([1, 2, 3]);
+    let (ptr, len, capacity) = vec.into_raw_vec_parts();
+    assert_eq!((len, capacity), (3, 8));
+
+    let vec = unsafe { HeapVec::from_raw_vec_parts(ptr, len, capacity) };
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec.capacity(), 8);
+
+    let boxed = vec.into_boxed_slice();
+    assert_eq!(*boxed, [1, 2, 3]);
+
+    let leaked = HeapVec::from(boxed).leak();
+    leaked[2] = 4;
+    assert_eq!(leaked, [1, 2, 4]);
+    drop(unsafe { Box::from_raw(leaked) });
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn heap_into_flattened() {
+    let mut vec = cl_generic_vec::HeapVec::with_capacity(4);
+    vec.extend([[1, 2], [3, 4], [5, 6]]);
+    let ptr = vec.as_ptr();
+
+    let mut vec = vec.into_flattened();
+    assert_eq!(vec.as_ptr(), ptr.cast());
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6]);
+    vec.extend([7, 8]);
+    assert!(vec.is_full());
+}
+
+#[test]
+fn array_into_flattened() {
+    use cl_generic_vec::raw::FlatArray;
+
+    let vec = ArrayVec::<[u8; 3], 2>::from_partial_array([[1, 2, 3]]);
+    let mut vec: GenericVec<u8, FlatArray<u8, 3, 2>> = vec.into_flattened();
+    assert_eq!(vec.capacity(), 6);
+    vec.push(4);
+    assert_eq!(vec, [1, 2, 3, 4]);
+}
+
+#[test]
+fn slice_into_flattened() {
+    let mut buffer = cl_generic_vec::uninit_array::<[u8; 2], 3>();
+    let mut vec = unsafe { cl_generic_vec::SliceVec::new(&mut buffer) };
+    vec.push([1, 2]);
+
+    let vec = vec.into_flattened();
+    assert_eq!(vec.capacity(), 6);
+    assert_eq!(vec, [1, 2]);
+}
+
+#[test]
+fn zeroizing_into_flattened() {
+    use cl_generic_vec::raw::Zeroizing;
+
+    let mut vec = GenericVec::<[u8; 2], Zeroizing<[_; 2]>>::new();
+    vec.push([1, 2]);
+    let vec = vec.into_flattened();
+    assert_eq!(vec, [1, 2]);
+    assert_eq!(vec.capacity(), 4);
+}
+
+#[test]
+fn zst_into_flattened() {
+    let vec = ArrayVec::<[(); 3], 4>::from_array([[(); 3]; 4]);
+    assert_eq!(vec.into_flattened().len(), 12);
+
+    let vec = ArrayVec::<[u8; 0], 4>::from_array([[]; 4]);
+    assert!(vec.into_flattened().is_empty());
+}
+
+#[test]
+fn split_at_spare_mut() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 2, 3]);
+
+    let (items, spare) = vec.split_at_spare_mut();
+    assert_eq!(spare.len(), 5);
+    for (item, slot) in items.iter_mut().zip(spare) {
+        slot.write(*item * 2);
+        *item = 0;
+    }
+    unsafe { vec.set_len(6) }
+    assert_eq!(vec, [0, 0, 0, 2, 4, 6]);
+
+    let mut vec = ArrayVec::<(), 2>::new();
+    vec.push(());
+    let (items, spare) = vec.split_at_spare_mut();
+    assert_eq!(items.len(), 1);
+    assert_eq!(spare.len(), isize::MAX as usize - 1);
+}
+
+#[test]
+fn extend_from_within() {
+    let mut vec = ArrayVec::<u8, 6>::from_partial_array([1, 2, 3]);
+    vec.extend_from_within(..2);
+    assert_eq!(vec, [1, 2, 3, 1, 2]);
+
+    assert!(vec.try_extend_from_within(1..3).is_err());
+    assert_eq!(vec, [1, 2, 3, 1, 2]);
+    assert!(vec.try_extend_from_within(4..).is_ok());
+    assert_eq!(vec, [1, 2, 3, 1, 2, 2]);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_within_out_of_bounds() {
+    let mut vec = ArrayVec::<u8, 6>::from_partial_array([1, 2, 3]);
+    vec.extend_from_within(2..4);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn extend_from_within_clone_panic() {
+    use std::rc::Rc;
+
+    struct Bomb(Rc<()>, bool);
+
+    impl Clone for Bomb {
+        fn clone(&self) -> Self {
+            assert!(!self.1);
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    let counter = Rc::new(());
+    let mut vec = ArrayVec::<Bomb, 8>::new();
+    vec.push(Bomb(counter.clone(), false));
+    vec.push(Bomb(counter.clone(), false));
+    vec.push(Bomb(counter.clone(), true));
+
+    #[cfg(feature = "std")]
+    {
+        let mut vec = std::panic::AssertUnwindSafe(&mut vec);
+        assert!(std::panic::catch_unwind(move || vec.extend_from_within(..)).is_err());
+        assert_eq!(Rc::strong_count(&counter), 4);
+    }
+
+    assert_eq!(vec.len(), 3);
+    drop(vec);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn push_within_capacity() {
+    let mut vec = ArrayVec::<u8, 1>::new();
+    assert_eq!(vec.push_within_capacity(1), Ok(&mut 1));
+    assert_eq!(vec.push_within_capacity(2), Err(2));
+}
+
+#[test]
+fn dedup() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 1, 2, 3, 3, 3, 1]);
+    vec.dedup();
+    assert_eq!(vec, [1, 2, 3, 1]);
+
+    let mut vec = ArrayVec::<(), 8>::from_partial_array([(), (), ()]);
+    vec.dedup();
+    assert_eq!(vec.len(), 1);
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn dedup_into() {
+    let mut vec = ArrayVec::<(u8, u8), 8>::from_partial_array([(1, 0), (1, 1), (2, 2), (2, 3), (2, 4), (3, 5)]);
+    let mut removed = ArrayVec::<(u8, u8), 8>::new();
+    vec.dedup_by_key_into(&mut removed, |item| item.0);
+    assert_eq!(vec, [(1, 0), (2, 2), (3, 5)]);
+    assert_eq!(removed, [(1, 1), (2, 3), (2, 4)]);
+
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 1, 2, 2]);
+    let mut removed = ArrayVec::<u8, 8>::from_partial_array([0]);
+    vec.dedup_into(&mut removed);
+    assert_eq!(vec, [1, 2]);
+    assert_eq!(removed, [0, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(not(feature = "no-panic"))]
+fn dedup_into_full() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 1, 2, 2]);
+    let mut removed = ArrayVec::<u8, 1>::new();
+    vec.dedup_into(&mut removed);
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn dedup_with_count() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 2, 2, 3, 3, 3, 2]);
+    let mut counts = ArrayVec::<usize, 8>::from_partial_array([9]);
+    vec.dedup_with_count(&mut counts);
+    assert_eq!(vec, [1, 2, 3, 2]);
+    assert_eq!(counts, [9, 1, 2, 3, 1]);
+
+    let mut vec = ArrayVec::<u8, 8>::new();
+    let mut counts = ArrayVec::<usize, 8>::new();
+    vec.dedup_with_count(&mut counts);
+    assert!(counts.is_empty());
+
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 3, 5, 2, 4]);
+    vec.dedup_by_with_count(&mut counts, |a, b| *a % 2 == *b % 2);
+    assert_eq!(vec, [1, 2]);
+    assert_eq!(counts, [3, 2]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn dedup_unsorted() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([3, 1, 3, 2, 1, 1, 4, 3]);
+    vec.dedup_unsorted();
+    assert_eq!(vec, [3, 1, 2, 4]);
+
+    let mut vec = ArrayVec::<(), 8>::from_partial_array([(), (), ()]);
+    vec.dedup_unsorted();
+    assert_eq!(vec.len(), 1);
+
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([10, 21, 12, 30, 23, 11]);
+    vec.dedup_by_key_unsorted(|x| *x % 10);
+    assert_eq!(vec, [10, 21, 12, 23]);
+}
+
+#[test]
+fn try_retain_into() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    let mut other = ArrayVec::<u8, 3>::new();
+
+    assert!(vec.try_retain_into(&mut other, |&mut x| x < 3 || x == 5).is_err());
+    assert_eq!(vec, [1, 2, 5, 7, 8]);
+    assert_eq!(other, [3, 4, 6]);
+
+    other.clear();
+    assert!(vec.try_retain_into(&mut other, |&mut x| x != 5).is_ok());
+    assert_eq!(vec, [1, 2, 7, 8]);
+    assert_eq!(other, [5]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn retain_into_panic() {
+    let mut vec = ArrayVec::<u8, 8>::from_partial_array([1, 2, 3, 4, 5, 6]);
+    let mut other = ArrayVec::<u8, 8>::new();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.retain_into(&mut other, |&mut x| {
+            assert!(x != 5);
+            x == 2
+        })
+    }));
+
+    assert!(result.is_err());
+    // the run of rejected elements that was being checked stays in the vector
+    assert_eq!(vec, [2, 3, 4, 5, 6]);
+    assert_eq!(other, [1]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[cfg(not(feature = "no-panic"))]
+fn partition_into() {
+    use std::boxed::Box;
+
+    let vec = ArrayVec::<u8, 8>::from_partial_array([1, 2, 3, 4, 5, 6, 7]);
+    let (small, large) = vec.partition_into::<[_; 3], Box<[_]>, _>(|&mut x| x < 4);
+    assert_eq!(small, [1, 2, 3]);
+    assert_eq!(large, [4, 5, 6, 7]);
+}
+
+#[test]
+fn fallible_collect() {
+    use cl_generic_vec::{iter::IteratorExt, uninit_array};
+
+    let vec = (0..4).try_collect_into::<ArrayVec<_, 4>>().unwrap();
+    assert_eq!(vec, [0, 1, 2, 3]);
+
+    // the size hint is too large for the array, but the iterator isn't
+    let vec = (0..10).filter(|x| x % 4 == 0).try_collect_into::<ArrayVec<_, 4>>().unwrap();
+    assert_eq!(vec, [0, 4, 8]);
+
+    let (vec, rest) = (0..7).try_collect_into::<ArrayVec<_, 4>>().unwrap_err();
+    assert_eq!(vec, [0, 1, 2, 3]);
+    assert!(rest.eq(4..7));
+
+    let mut pulled = 0;
+    let vec = (0..7).inspect(|_| pulled += 1).collect_truncating::<ArrayVec<_, 4>>();
+    assert_eq!(vec, [0, 1, 2, 3]);
+    assert_eq!(pulled, 4);
+
+    let mut buffer = uninit_array::<_, 2>();
+    let (vec, rest) = (0..3).collect_in(&mut buffer).unwrap_err();
+    assert_eq!(vec, [0, 1]);
+    assert!(rest.eq([2]));
+
+    #[cfg(feature = "alloc")]
+    {
+        let vec = (0..100).collect_truncating::<cl_generic_vec::HeapVec<_>>();
+        assert!(vec.iter().copied().eq(0..100));
+    }
+}
+
+#[test]
+fn array_vec_from_fn() {
+    let vec = ArrayVec::<_, 3>::from_fn(|i| i * 10);
+    assert_eq!(vec, [0, 10, 20]);
+
+    let mut calls = 0;
+    let result = ArrayVec::<_, 4>::try_from_fn(|i| {
+        calls += 1;
+        if i == 2 { Err(i) } else { Ok(i * 10) }
+    });
+    assert_eq!(result.unwrap_err(), 2);
+    assert_eq!(calls, 3);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn heap_size() {
+    use cl_generic_vec::{raw::Zeroizing, HeapSize, HeapVec, SliceVec};
+    use core::mem::size_of;
+    use std::{boxed::Box, string::String};
+
+    let mut nested = HeapVec::<HeapVec<String>>::new();
+    assert_eq!(nested.heap_size(), 0);
+
+    let mut inner = HeapVec::with_capacity(3);
+    inner.push(String::with_capacity(10));
+    inner.push(String::new());
+    nested.push(inner);
+
+    let inner_size = 3 * size_of::<String>() + 10;
+    assert_eq!(nested.heap_size(), nested.capacity() * size_of::<HeapVec<String>>() + inner_size);
+
+    let mut array = ArrayVec::<Box<u64>, 4>::new();
+    array.push(Box::new(1));
+    array.push(Box::new(2));
+    assert_eq!(array.heap_size(), 2 * size_of::<u64>());
+
+    let mut buffer = cl_generic_vec::uninit_array::<Option<Box<[u8]>>, 4>();
+    let mut slice = unsafe { SliceVec::new(&mut buffer) };
+    slice.push(Some(std::vec![0; 7].into_boxed_slice()));
+    slice.push(None);
+    assert_eq!(slice.heap_size(), 7);
+
+    let mut zeroizing = GenericVec::<u32, Zeroizing<Box<[_]>>>::with_capacity(5);
+    zeroizing.push(1);
+    assert_eq!(zeroizing.heap_size(), zeroizing.capacity() * size_of::<u32>());
+}